@@ -1,4 +1,4 @@
-use std::{path::PathBuf, time::SystemTime};
+use std::{collections::HashMap, path::PathBuf, time::SystemTime};
 use sys_locale::get_locale;
 
 use anyhow::anyhow;
@@ -49,6 +49,8 @@ pub struct AppConfigFromFile {
     use_tor: bool,
     #[serde(default = "default_false")]
     paper_wallet_enabled: bool,
+    #[serde(default)]
+    sensor_display_names: HashMap<String, String>,
 }
 
 impl Default for AppConfigFromFile {
@@ -72,6 +74,7 @@ impl Default for AppConfigFromFile {
             airdrop_ui_enabled: true,
             paper_wallet_enabled: false,
             use_tor: true,
+            sensor_display_names: HashMap::new(),
         }
     }
 }
@@ -121,6 +124,7 @@ pub(crate) struct AppConfig {
     airdrop_ui_enabled: bool,
     paper_wallet_enabled: bool,
     use_tor: bool,
+    sensor_display_names: HashMap<String, String>,
 }
 
 impl AppConfig {
@@ -145,6 +149,7 @@ impl AppConfig {
             airdrop_ui_enabled: true,
             use_tor: true,
             paper_wallet_enabled: false,
+            sensor_display_names: HashMap::new(),
         }
     }
 
@@ -185,6 +190,7 @@ impl AppConfig {
                 self.airdrop_ui_enabled = config.airdrop_ui_enabled;
                 self.use_tor = config.use_tor;
                 self.paper_wallet_enabled = config.paper_wallet_enabled;
+                self.sensor_display_names = config.sensor_display_names;
             }
             Err(e) => {
                 warn!(target: LOG_TARGET, "Failed to parse app config: {}", e.to_string());
@@ -368,6 +374,20 @@ impl AppConfig {
         Ok(())
     }
 
+    /// Friendly names overriding the built-in sensor display names
+    pub fn sensor_display_names(&self) -> &HashMap<String, String> {
+        &self.sensor_display_names
+    }
+
+    pub async fn set_sensor_display_names(
+        &mut self,
+        sensor_display_names: HashMap<String, String>,
+    ) -> Result<(), anyhow::Error> {
+        self.sensor_display_names = sensor_display_names;
+        self.update_config_file().await?;
+        Ok(())
+    }
+
     // Allow needless update because in future there may be fields that are
     // missing
     #[allow(clippy::needless_update)]
@@ -396,6 +416,7 @@ impl AppConfig {
             airdrop_ui_enabled: self.airdrop_ui_enabled,
             paper_wallet_enabled: self.paper_wallet_enabled,
             use_tor: self.use_tor,
+            sensor_display_names: self.sensor_display_names.clone(),
         };
         let config = serde_json::to_string(config)?;
         debug!(target: LOG_TARGET, "Updating config file: {:?} {:?}", file, self.clone());
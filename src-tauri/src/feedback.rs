@@ -13,6 +13,7 @@ use zip::ZipWriter;
 
 use crate::app_config::AppConfig;
 use crate::app_in_memory_config::AppInMemoryConfig;
+use crate::hardware_monitor::HardwareMonitor;
 use crate::utils::file_utils::{make_relative_path, path_as_string};
 
 const LOG_TARGET: &str = "tari::universe::feedback";
@@ -108,9 +109,12 @@ impl Feedback {
 
         // Create a multipart form
         let app_id = self.config.read().await.anon_id().to_string();
+        let hardware_monitor_config =
+            serde_json::to_string(&HardwareMonitor::current().read().await.effective_config())?;
         let mut form = multipart::Form::new()
             .text("feedback", feedback_message.clone())
-            .text("appId", app_id.clone());
+            .text("appId", app_id.clone())
+            .text("hardwareMonitorConfig", hardware_monitor_config);
 
         let upload_zip_path = if include_logs {
             let logs_dir = &app_log_dir.ok_or(anyhow::anyhow!("Missing log directory"))?;
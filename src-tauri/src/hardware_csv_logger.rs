@@ -0,0 +1,322 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    sync::mpsc::{self, SyncSender, TrySendError},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::anyhow;
+use log::warn;
+
+use crate::hardware_monitor::{
+    session_extremes, Availability, HardwareParameters, HardwareStatus, ReadingSource,
+    TemperatureUnit,
+};
+
+const LOG_TARGET: &str = "tari::universe::hardware_csv_logger";
+
+/// Size a CSV log may grow to before it is rotated, unless configured otherwise
+pub const DEFAULT_CSV_LOG_MAX_SIZE_BYTES: u64 = 10 * 1024 * 1024;
+/// Reads waiting to be written, further reads are dropped while the disk cannot keep up
+const CSV_LOG_QUEUE_LENGTH: usize = 64;
+
+/// Columns every row starts with, followed by a `GPU_COLUMN_SUFFIXES` group per GPU
+const LEADING_COLUMNS: [&str; 3] = ["timestamp_ms", "cpu_temperature", "cpu_usage"];
+const GPU_COLUMN_SUFFIXES: [&str; 3] = [" temperature", " usage", " power_watts"];
+/// The log does not record the CPU model, replayed rows name it generically
+const REPLAYED_CPU_LABEL: &str = "CPU";
+
+/// Appends one row per hardware read to a CSV file, temperatures in Celsius.
+/// The file is rotated to `<path>.1` once it exceeds its size limit, or when the set of
+/// GPUs changes so that every file has a single header matching its rows. A file left
+/// over from a previous session is rotated the same way when its header differs.
+pub struct HardwareCsvLogger {
+    path: PathBuf,
+    max_size_bytes: u64,
+    /// GPUs named in the header of the current file, `None` until the header is written
+    gpu_labels: Option<Vec<String>>,
+}
+
+impl HardwareCsvLogger {
+    pub fn new(path: PathBuf) -> Self {
+        HardwareCsvLogger {
+            path,
+            max_size_bytes: DEFAULT_CSV_LOG_MAX_SIZE_BYTES,
+            gpu_labels: None,
+        }
+    }
+
+    pub fn set_max_size(&mut self, bytes: u64) {
+        self.max_size_bytes = bytes;
+    }
+
+    pub fn log(
+        &mut self,
+        sample_time: SystemTime,
+        cpu: Option<&HardwareParameters>,
+        gpu: &[HardwareParameters],
+    ) -> Result<(), anyhow::Error> {
+        let gpu_labels: Vec<String> = gpu.iter().map(|gpu| gpu.label.clone()).collect();
+        let header = csv_header(&gpu_labels);
+        let row = csv_row(sample_time, cpu, gpu);
+        let size = fs::metadata(&self.path).map_or(0, |metadata| metadata.len());
+        let gpus_changed = match &self.gpu_labels {
+            Some(labels) => *labels != gpu_labels,
+            None => size > 0 && read_header(&self.path).as_deref() != Some(header.trim_end()),
+        };
+        if size > 0 && (gpus_changed || size + row.len() as u64 > self.max_size_bytes) {
+            fs::rename(&self.path, rotated_path(&self.path))?;
+            self.gpu_labels = None;
+        }
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        // A file left over from a previous session already starts with its header
+        if self.gpu_labels.is_none() && file.metadata()?.len() == 0 {
+            file.write_all(header.as_bytes())?;
+        }
+        file.write_all(row.as_bytes())?;
+        self.gpu_labels = Some(gpu_labels);
+        Ok(())
+    }
+}
+
+/// Hands every read to a `HardwareCsvLogger` on its own thread, so file IO never runs
+/// while the hardware monitor is locked. The thread stops once the writer is dropped.
+pub struct CsvLogWriter {
+    sender: SyncSender<CsvLogEntry>,
+}
+
+struct CsvLogEntry {
+    sample_time: SystemTime,
+    cpu: Option<HardwareParameters>,
+    gpu: Vec<HardwareParameters>,
+}
+
+impl CsvLogWriter {
+    pub fn spawn(mut logger: HardwareCsvLogger) -> Self {
+        let (sender, receiver) = mpsc::sync_channel::<CsvLogEntry>(CSV_LOG_QUEUE_LENGTH);
+        std::thread::spawn(move || {
+            for entry in receiver {
+                if let Err(e) = logger.log(entry.sample_time, entry.cpu.as_ref(), &entry.gpu) {
+                    warn!(target: LOG_TARGET, "Failed to write hardware CSV log: {}", e);
+                }
+            }
+        });
+        CsvLogWriter { sender }
+    }
+
+    pub fn log(
+        &self,
+        sample_time: SystemTime,
+        cpu: Option<&HardwareParameters>,
+        gpu: &[HardwareParameters],
+    ) {
+        let entry = CsvLogEntry {
+            sample_time,
+            cpu: cpu.cloned(),
+            gpu: gpu.to_vec(),
+        };
+        match self.sender.try_send(entry) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) => {
+                warn!(target: LOG_TARGET, "Hardware CSV log is falling behind, dropping a read");
+            }
+            Err(TrySendError::Disconnected(_)) => {
+                warn!(target: LOG_TARGET, "Hardware CSV log writer stopped");
+            }
+        }
+    }
+}
+
+/// First line of an existing log, `None` when it cannot be read
+fn read_header(path: &Path) -> Option<String> {
+    let mut header = String::new();
+    BufReader::new(File::open(path).ok()?)
+        .read_line(&mut header)
+        .ok()?;
+    Some(header.trim_end().to_string())
+}
+
+fn rotated_path(path: &Path) -> PathBuf {
+    let mut rotated = path.as_os_str().to_owned();
+    rotated.push(".1");
+    PathBuf::from(rotated)
+}
+
+pub(crate) fn csv_header(gpu_labels: &[String]) -> String {
+    let mut columns: Vec<String> = LEADING_COLUMNS.iter().map(|c| c.to_string()).collect();
+    for label in gpu_labels {
+        // Commas in a label would shift every following column
+        let label = label.replace(',', " ");
+        columns.extend(
+            GPU_COLUMN_SUFFIXES
+                .iter()
+                .map(|suffix| format!("{}{}", label, suffix)),
+        );
+    }
+    columns.join(",") + "\n"
+}
+
+pub(crate) fn csv_row(
+    sample_time: SystemTime,
+    cpu: Option<&HardwareParameters>,
+    gpu: &[HardwareParameters],
+) -> String {
+    let timestamp = sample_time
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_millis());
+    let mut values = vec![
+        timestamp.to_string(),
+        cpu.map_or(String::new(), |cpu| {
+            format!("{:.1}", cpu.current_temperature)
+        }),
+        cpu.map_or(String::new(), |cpu| format!("{:.1}", cpu.usage_percentage)),
+    ];
+    for gpu in gpu {
+        values.push(format!("{:.1}", gpu.current_temperature));
+        values.push(format!("{:.1}", gpu.usage_percentage));
+        values.push(
+            gpu.power_draw_watts
+                .map_or(String::new(), |power| format!("{:.1}", power)),
+        );
+    }
+    values.join(",") + "\n"
+}
+
+/// Reads a log written by `HardwareCsvLogger` back into one status per row, so it can be
+/// replayed. Only the logged columns are restored, the session extremes are tracked again.
+pub(crate) fn parse_csv_log(content: &str) -> Result<Vec<HardwareStatus>, anyhow::Error> {
+    let mut lines = content.lines().filter(|line| !line.trim().is_empty());
+    let header = lines.next().ok_or_else(|| anyhow!("CSV log is empty"))?;
+    let gpu_labels = parse_csv_header(header)?;
+    let column_count = LEADING_COLUMNS.len() + gpu_labels.len() * GPU_COLUMN_SUFFIXES.len();
+
+    let mut statuses: Vec<HardwareStatus> = vec![];
+    for (row, line) in lines.enumerate() {
+        let values: Vec<&str> = line.split(',').collect();
+        if values.len() != column_count {
+            return Err(anyhow!(
+                "Row {} of the CSV log has {} columns instead of {}",
+                row + 1,
+                values.len(),
+                column_count
+            ));
+        }
+        let previous = statuses.last();
+        let timestamp_ms: u64 = values[0]
+            .parse()
+            .map_err(|e| anyhow!("Invalid timestamp in row {}: {}", row + 1, e))?;
+        let cpu = match csv_value(values[1])? {
+            Some(temperature) => Some(replayed_device(
+                REPLAYED_CPU_LABEL,
+                temperature,
+                csv_value(values[2])?,
+                previous.and_then(|p| p.cpu.as_ref()),
+            )),
+            None => None,
+        };
+        let gpu = gpu_labels
+            .iter()
+            .zip(values[LEADING_COLUMNS.len()..].chunks_exact(GPU_COLUMN_SUFFIXES.len()))
+            .enumerate()
+            .map(|(i, (label, gpu_values))| {
+                let mut gpu = replayed_device(
+                    label,
+                    csv_value(gpu_values[0])?.unwrap_or_default(),
+                    csv_value(gpu_values[1])?,
+                    previous.and_then(|p| p.gpu.get(i)),
+                );
+                gpu.power_draw_watts = csv_value(gpu_values[2])?;
+                Ok(gpu)
+            })
+            .collect::<Result<Vec<HardwareParameters>, anyhow::Error>>()?;
+
+        statuses.push(HardwareStatus {
+            cpu_availability: if cpu.is_some() {
+                Availability::Available
+            } else {
+                Availability::Unavailable
+            },
+            cpu,
+            gpu,
+            motherboard: vec![],
+            disks: vec![],
+            gpu_availability: Availability::Available,
+            temperature_unit: TemperatureUnit::Celsius,
+            implausible_readings: false,
+            sample_time: UNIX_EPOCH + Duration::from_millis(timestamp_ms),
+            source: ReadingSource::Simulated,
+            rig_id: None,
+            alerts: vec![],
+            memory: None,
+            power_source: None,
+            battery_percentage: None,
+        });
+    }
+    Ok(statuses)
+}
+
+/// The GPU labels named by the header, which has to match the columns the logger writes
+fn parse_csv_header(header: &str) -> Result<Vec<String>, anyhow::Error> {
+    let columns: Vec<&str> = header.split(',').collect();
+    if !columns.starts_with(&LEADING_COLUMNS)
+        || (columns.len() - LEADING_COLUMNS.len()) % GPU_COLUMN_SUFFIXES.len() != 0
+    {
+        return Err(anyhow!("Unexpected CSV log header: {}", header));
+    }
+    columns[LEADING_COLUMNS.len()..]
+        .chunks_exact(GPU_COLUMN_SUFFIXES.len())
+        .map(|group| {
+            let label = group[0]
+                .strip_suffix(GPU_COLUMN_SUFFIXES[0])
+                .ok_or_else(|| anyhow!("Unexpected GPU column in CSV log: {}", group[0]))?;
+            let matches = group
+                .iter()
+                .zip(GPU_COLUMN_SUFFIXES)
+                .all(|(column, suffix)| column.strip_suffix(suffix) == Some(label));
+            if matches {
+                Ok(label.to_string())
+            } else {
+                Err(anyhow!(
+                    "Unexpected GPU columns in CSV log: {}",
+                    group.join(",")
+                ))
+            }
+        })
+        .collect()
+}
+
+/// An empty cell is a value the device did not report
+fn csv_value(cell: &str) -> Result<Option<f32>, anyhow::Error> {
+    let cell = cell.trim();
+    if cell.is_empty() {
+        return Ok(None);
+    }
+    cell.parse()
+        .map(Some)
+        .map_err(|e| anyhow!("Invalid value {:?} in CSV log: {}", cell, e))
+}
+
+fn replayed_device(
+    label: &str,
+    temperature: f32,
+    usage: Option<f32>,
+    previous: Option<&HardwareParameters>,
+) -> HardwareParameters {
+    let (max_temperature, min_temperature) = session_extremes(previous, temperature);
+    HardwareParameters {
+        label: label.to_string(),
+        usage_percentage: usage.unwrap_or_default(),
+        current_temperature: temperature,
+        max_temperature,
+        min_temperature,
+        ..Default::default()
+    }
+}
@@ -1,34 +1,222 @@
-use std::{fs, ops::Deref, path::PathBuf, sync::LazyLock};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt, fs,
+    ops::Deref,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc, Arc, LazyLock, Mutex, MutexGuard, Once, PoisonError, Weak,
+    },
+    time::{Duration, Instant, SystemTime},
+};
 
 use anyhow::anyhow;
-use log::{debug, trace, warn};
-use nvml_wrapper::{enum_wrappers::device::TemperatureSensor, Nvml};
+use log::{debug, error, info, trace, warn};
+use notify::{RecursiveMode, Watcher};
+use nvml_wrapper::{
+    bitmasks::device::ThrottleReasons,
+    enum_wrappers::device::{
+        Clock, EccCounter, MemoryError, PcieUtilCounter, TemperatureSensor, TemperatureThreshold,
+    },
+    enums::device::SampleValue,
+    error::NvmlError,
+    structs::device::FieldId,
+    Device, Nvml,
+};
 use serde::{Deserialize, Serialize};
-use sysinfo::{Component, Components, CpuRefreshKind, RefreshKind, System};
-use tokio::sync::RwLock;
+use sysinfo::{Component, Components, Cpu, CpuRefreshKind, RefreshKind, System};
+use tokio::sync::{broadcast, RwLock};
+
+use crate::{
+    hardware_csv_logger::{self, CsvLogWriter, HardwareCsvLogger},
+    ioreg,
+    libre_hardware_monitor::LibreHardwareMonitorReader,
+    nvidia_smi,
+    power_supply::{self, PowerSource, PowerStatus},
+    prometheus_metrics,
+};
 
 const LOG_TARGET: &str = "tari::universe::hardware_monitor";
 static INSTANCE: LazyLock<RwLock<HardwareMonitor>> =
     LazyLock::new(|| RwLock::new(HardwareMonitor::new()));
 
+pub type EccErrorCallback = Box<dyn Fn(&str, u64) + Send + Sync>;
+pub type NewPeakCallback = Box<dyn Fn(&str, f32) + Send + Sync>;
+/// Called with the GPU uuid, its temperature and the alert threshold, in the configured unit
+pub type TemperatureAlertCallback = Box<dyn Fn(&str, f32, f32) + Send + Sync>;
+
 enum CurrentOperatingSystem {
     Windows,
     Linux,
     MacOS,
+    Other,
 }
 
-#[derive(Clone, Debug, Serialize)]
+/// Hardware monitor backend in use, useful for diagnostics and platform-specific UI
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum ImplementationKind {
+    Windows,
+    Linux,
+    MacOS,
+    Fallback,
+    Replay,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct HardwareParameters {
     pub label: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
     pub usage_percentage: f32,
     pub current_temperature: f32,
     pub max_temperature: f32,
+    /// Lowest temperature seen this session, `f32::MAX` until a first reading
+    #[serde(default = "unset_min_temperature")]
+    pub min_temperature: f32,
+    #[serde(default)]
+    pub sockets: Vec<SocketTemperature>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uncorrected_ecc_errors: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub efficiency: Option<f32>,
+    #[serde(default)]
+    pub is_integrated: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu_tjmax: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slowdown_temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connected_displays: Option<u32>,
+    /// Current power draw from NVML's `power_usage()`, which reports milliwatts, or nvidia-smi.
+    /// `None` where the device does not report it or the read fails.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub power_draw_watts: Option<f32>,
+    /// Speed of each fan as a percentage of its maximum, a stopped fan next to spinning ones has failed
+    #[serde(default)]
+    pub fan_speeds_percentage: Vec<u32>,
+    /// Speed of the fastest fan
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fan_speed_percentage: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_frequency_mhz: Option<u32>,
+    /// Frequency of the fastest core
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_frequency_mhz: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_frequency_mhz: Option<u32>,
+    /// Thermal design power, to put `power_draw_watts` in context
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tdp_watts: Option<f32>,
+    /// Usage of the CPU-visible window into GPU memory
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bar1_memory_used_bytes: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bar1_memory_total_bytes: Option<u64>,
+    /// Sensors the device answered when first probed, `None` where no probing is done
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supported_sensors: Option<SensorSupport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub used_memory_mb: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_memory_mb: Option<f32>,
+    /// Usage of every logical core, `usage_percentage` is their average
+    #[serde(default)]
+    pub core_usages: Vec<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub core_clock_mhz: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_clock_mhz: Option<f32>,
+    /// Why the GPU is running below its maximum clocks, empty when it is not held back
+    #[serde(default)]
+    pub throttle_reasons: Vec<String>,
+    /// Mean of the last readings within the monitor's averaging window, smooths trend lines
+    #[serde(default)]
+    pub average_temperature: f32,
+    /// Frequency of every logical core, empty where the platform does not report it
+    #[serde(default)]
+    pub core_frequencies_mhz: Vec<u32>,
+    /// Memory junction temperature, `None` on GPUs without a memory sensor
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_temperature: Option<f32>,
+    /// NVENC load, `None` where the card or driver does not report it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encoder_utilization: Option<f32>,
+    /// NVDEC load, `None` where the card or driver does not report it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decoder_utilization: Option<f32>,
+    /// Manufacturer of a GPU, `Unknown` for CPUs and motherboard sensors
+    #[serde(default)]
+    pub vendor: GpuVendor,
+    /// Highest power draw seen this session, transient spikes hidden by the average
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_power_watts: Option<f32>,
+    /// Usage of the performance cores of a hybrid CPU, `None` where core types are not known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub p_core_usage: Option<f32>,
+    /// Usage of the efficiency cores of a hybrid CPU, `None` where core types are not known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub e_core_usage: Option<f32>,
+    /// Package or Tctl temperature of the CPU, `None` where no package sensor is known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package_temperature: Option<f32>,
+    /// Per-CCD or per-core CPU temperatures, empty where the platform does not report them
+    #[serde(default)]
+    pub core_temperatures: Vec<f32>,
+    /// PCIe receive throughput in KB/s, `None` where the GPU does not report it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pcie_rx_kb: Option<f32>,
+    /// PCIe transmit throughput in KB/s, `None` where the GPU does not report it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pcie_tx_kb: Option<f32>,
+    /// Current PCIe link generation, a card on a bad riser falls back to Gen1
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pcie_link_gen: Option<u32>,
+    /// Current PCIe link width in lanes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pcie_link_width: Option<u32>,
+    /// How long the GPU has been throttling without a break, 0 while it runs unthrottled
+    #[serde(default)]
+    pub throttle_duration_ms: u64,
+    /// Mean of the AMD Tccd sensors, `None` on CPUs without per-CCD sensors
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ccd_average_temperature: Option<f32>,
+    /// Hottest AMD Tccd sensor, `None` on CPUs without per-CCD sensors
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ccd_max_temperature: Option<f32>,
+    /// Memory controller load, high on memory-bound algorithms even when the cores are idle
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_utilization: Option<f32>,
+    /// Set while the temperature trend projects the GPU crossing the configured thermal
+    /// limit within the prediction horizon, before it actually throttles
+    #[serde(default)]
+    pub approaching_limit: bool,
+}
+
+/// Which optional sensors a GPU supports, so the UI only shows readings that can exist
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SensorSupport {
+    pub power: bool,
+    pub fan_speed: bool,
+    pub ecc: bool,
+    pub slowdown_temperature: bool,
+    pub bar1_memory: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SocketTemperature {
+    pub socket_id: u32,
+    pub current_temperature: f32,
+    pub max_temperature: f32,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GpuStatus {
     pub device_name: String,
     pub is_available: bool,
+    /// Excluded by the user, its readings are left out of the hardware status
+    #[serde(default)]
+    pub is_excluded: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -36,307 +224,3666 @@ pub struct GpuStatusFile {
     pub gpu_devices: Vec<GpuStatus>,
 }
 
+fn unset_min_temperature() -> f32 {
+    f32::MAX
+}
+
+/// Session maximum and minimum after a new reading, starting from the reading on the first poll
+pub(crate) fn session_extremes(
+    previous: Option<&HardwareParameters>,
+    current_temperature: f32,
+) -> (f32, f32) {
+    match previous {
+        Some(previous) => (
+            previous.max_temperature.max(current_temperature),
+            previous.min_temperature.min(current_temperature),
+        ),
+        None => (current_temperature, current_temperature),
+    }
+}
+
+/// Session peak power draw after a new reading, `None` until the device reports its power
+pub(crate) fn session_max_power(
+    previous: Option<&HardwareParameters>,
+    power_draw_watts: Option<f32>,
+) -> Option<f32> {
+    match (previous.and_then(|p| p.max_power_watts), power_draw_watts) {
+        (Some(max_power), Some(power)) => Some(max_power.max(power)),
+        (max_power, power) => max_power.or(power),
+    }
+}
+
 impl Default for HardwareParameters {
     fn default() -> Self {
         HardwareParameters {
             label: "N/A".to_string(),
+            uuid: None,
             usage_percentage: 0.0,
             current_temperature: 0.0,
             max_temperature: 0.0,
+            min_temperature: unset_min_temperature(),
+            sockets: vec![],
+            uncorrected_ecc_errors: None,
+            efficiency: None,
+            is_integrated: false,
+            cpu_tjmax: None,
+            slowdown_temperature: None,
+            connected_displays: None,
+            power_draw_watts: None,
+            fan_speeds_percentage: vec![],
+            fan_speed_percentage: None,
+            base_frequency_mhz: None,
+            current_frequency_mhz: None,
+            max_frequency_mhz: None,
+            tdp_watts: None,
+            bar1_memory_used_bytes: None,
+            bar1_memory_total_bytes: None,
+            supported_sensors: None,
+            used_memory_mb: None,
+            total_memory_mb: None,
+            core_usages: vec![],
+            core_clock_mhz: None,
+            memory_clock_mhz: None,
+            throttle_reasons: vec![],
+            average_temperature: 0.0,
+            core_frequencies_mhz: vec![],
+            memory_temperature: None,
+            encoder_utilization: None,
+            decoder_utilization: None,
+            vendor: GpuVendor::Unknown,
+            max_power_watts: None,
+            p_core_usage: None,
+            e_core_usage: None,
+            package_temperature: None,
+            core_temperatures: vec![],
+            pcie_rx_kb: None,
+            pcie_tx_kb: None,
+            pcie_link_gen: None,
+            pcie_link_width: None,
+            throttle_duration_ms: 0,
+            ccd_average_temperature: None,
+            ccd_max_temperature: None,
+            memory_utilization: None,
+            approaching_limit: false,
         }
     }
 }
 
-#[derive(Debug, Serialize, Clone)]
+/// System memory and swap usage
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct MemoryParameters {
+    pub used_mb: f32,
+    pub total_mb: f32,
+    pub swap_used_mb: f32,
+    pub swap_total_mb: f32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct HardwareStatus {
     pub cpu: Option<HardwareParameters>,
     pub gpu: Vec<HardwareParameters>,
+    #[serde(default)]
+    pub motherboard: Vec<HardwareParameters>,
+    /// NVMe drive temperatures, always empty on Windows where sysinfo lists no drive sensors
+    #[serde(default)]
+    pub disks: Vec<HardwareParameters>,
+    #[serde(default)]
+    pub cpu_availability: Availability,
+    #[serde(default)]
+    pub gpu_availability: Availability,
+    #[serde(default)]
+    pub temperature_unit: TemperatureUnit,
+    /// Set when the readings are physically implausible, hinting at a misdetected sensor
+    #[serde(default)]
+    pub implausible_readings: bool,
+    /// Captured once per poll and shared by every device, so CPU and GPU series line up on charts.
+    /// Written as plain epoch milliseconds, so logs kept on disk are easy to read
+    #[serde(default = "SystemTime::now", with = "epoch_millis")]
+    pub sample_time: SystemTime,
+    #[serde(default)]
+    pub source: ReadingSource,
+    /// Identifies the machine the status was read on when statuses of several rigs are merged
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rig_id: Option<String>,
+    /// Devices that crossed a warning or critical threshold since the previous poll
+    #[serde(default)]
+    pub alerts: Vec<ThermalAlert>,
+    /// RAM and swap usage, `None` where the backend or the replayed row has no memory readings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<MemoryParameters>,
+    /// Whether a laptop runs on AC or battery, `None` on machines without a battery
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub power_source: Option<PowerSource>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub battery_percentage: Option<f32>,
 }
 
-trait HardwareMonitorImpl: Send + Sync + 'static {
-    fn _get_implementation_name(&self) -> String;
-    fn read_cpu_parameters(
-        &self,
-        current_parameters: Option<HardwareParameters>,
-    ) -> HardwareParameters;
-    fn read_gpu_parameters(
-        &self,
-        current_parameters: Vec<HardwareParameters>,
-    ) -> Vec<HardwareParameters>;
-    fn read_gpu_devices(&self) -> Vec<GpuStatus>;
-    fn load_status_file(&mut self, config_path: PathBuf) -> Result<(), anyhow::Error>;
-    fn _log_all_components(&self);
+/// (De)serializes a `SystemTime` as milliseconds since the Unix epoch,
+/// statuses logged before still hold serde's default `SystemTime` representation
+mod epoch_millis {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(time: &SystemTime, serializer: S) -> Result<S::Ok, S::Error> {
+        let millis = time.duration_since(UNIX_EPOCH).map_or(0, |elapsed| {
+            u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX)
+        });
+        serializer.serialize_u64(millis)
+    }
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum EpochTime {
+        Millis(u64),
+        SystemTime(SystemTime),
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<SystemTime, D::Error> {
+        Ok(match EpochTime::deserialize(deserializer)? {
+            EpochTime::Millis(millis) => UNIX_EPOCH + Duration::from_millis(millis),
+            EpochTime::SystemTime(time) => time,
+        })
+    }
 }
 
-pub struct HardwareMonitor {
-    #[allow(dead_code)]
-    current_os: CurrentOperatingSystem,
-    current_implementation: Box<dyn HardwareMonitorImpl>,
-    cpu: Option<HardwareParameters>,
-    gpu: Vec<HardwareParameters>,
-    gpu_devices: Vec<GpuStatus>,
+/// Summary of one rig in a fleet-wide view, temperatures in Celsius
+#[derive(Clone, Debug, Serialize)]
+pub struct RigSummary {
+    pub rig_id: String,
+    pub cpu: Option<HardwareParameters>,
+    pub gpu_count: usize,
+    pub average_gpu_temperature: Option<f32>,
+    pub max_temperature: Option<f32>,
+    pub total_power_draw_watts: Option<f32>,
+    pub implausible_readings: bool,
 }
 
-impl HardwareMonitor {
-    pub fn new() -> Self {
-        HardwareMonitor {
-            current_os: HardwareMonitor::detect_current_os(),
-            current_implementation: match HardwareMonitor::detect_current_os() {
-                CurrentOperatingSystem::Windows => Box::new(WindowsHardwareMonitor {
-                    nvml: HardwareMonitor::initialize_nvml(),
-                    gpu_status_file: None,
-                }),
-                CurrentOperatingSystem::Linux => Box::new(LinuxHardwareMonitor {
-                    nvml: HardwareMonitor::initialize_nvml(),
-                    gpu_status_file: None,
-                }),
-                CurrentOperatingSystem::MacOS => Box::new(MacOSHardwareMonitor {
-                    gpu_status_file: None,
-                }),
-            },
-            cpu: None,
-            gpu: vec![],
-            gpu_devices: vec![],
+/// Readings of several rigs combined into fleet totals and a per-rig breakdown
+#[derive(Clone, Debug, Serialize)]
+pub struct AggregatedStatus {
+    pub rig_count: usize,
+    pub gpu_count: usize,
+    pub average_gpu_temperature: Option<f32>,
+    pub max_temperature: Option<f32>,
+    pub total_power_draw_watts: Option<f32>,
+    pub rigs: Vec<RigSummary>,
+}
+
+/// Cached readings older than this are reported as stale
+const STALE_READING_AGE: Duration = Duration::from_secs(10);
+
+/// Where a status came from, so consumers can tell live readings from recycled ones
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReadingSource {
+    #[default]
+    Fresh,
+    Cached,
+    Simulated,
+    Stale,
+}
+
+impl ReadingSource {
+    /// Source of a previously read sample that is handed out again
+    pub fn of_cached_sample(sample_time: SystemTime) -> Self {
+        match sample_time.elapsed() {
+            Ok(age) if age <= STALE_READING_AGE => ReadingSource::Cached,
+            _ => ReadingSource::Stale,
+        }
+    }
+}
+
+/// Thresholds of the sanity check run on every poll
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct PlausibilityThresholds {
+    /// CPU usage above which the CPU is considered loaded
+    pub cpu_load_percentage: f32,
+    /// A loaded CPU reading at or below this temperature points to a broken sensor
+    pub min_loaded_cpu_temperature: f32,
+    /// Largest believable difference between the CPU and any GPU
+    pub max_cpu_gpu_divergence: f32,
+}
+
+impl Default for PlausibilityThresholds {
+    fn default() -> Self {
+        PlausibilityThresholds {
+            cpu_load_percentage: 50.0,
+            min_loaded_cpu_temperature: 1.0,
+            max_cpu_gpu_divergence: 50.0,
         }
     }
+}
 
-    pub fn current() -> &'static RwLock<HardwareMonitor> {
-        &INSTANCE
+/// A device has to cool this far below a threshold before its level drops,
+/// so a temperature hovering at the threshold does not alert on every poll
+const THERMAL_ALERT_HYSTERESIS: f32 = 3.0;
+
+/// How far ahead the temperature trend is projected when predicting a thermal limit crossing
+const THERMAL_LIMIT_PREDICTION_HORIZON: Duration = Duration::from_secs(30);
+/// Reads the temperature trend is taken over, few enough to follow a sudden load change
+const THERMAL_LIMIT_PREDICTION_SAMPLES: usize = 5;
+
+/// Extrapolates the trend between the first and last sample `horizon` past the last one,
+/// `None` until two samples some time apart exist
+pub(crate) fn projected_temperature(samples: &[HistorySample], horizon: Duration) -> Option<f32> {
+    let (first, last) = (samples.first()?, samples.last()?);
+    let elapsed = last.sample_time.duration_since(first.sample_time).ok()?;
+    if elapsed.is_zero() {
+        return None;
     }
+    let rate = (last.temperature - first.temperature) / elapsed.as_secs_f32();
+    Some(last.temperature + rate * horizon.as_secs_f32())
+}
 
-    fn initialize_nvml() -> Option<Nvml> {
-        let nvml = Nvml::init();
-        match nvml {
-            Ok(nvml) => {
-                debug!(target: LOG_TARGET, "NVML initialized");
-                Some(nvml)
-            }
-            Err(e) => {
-                warn!(target: LOG_TARGET, "Failed to initialize NVML: {}", e);
-                None
-            }
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ComponentKind {
+    Cpu,
+    Gpu,
+    Motherboard,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ThermalLevel {
+    #[default]
+    Normal,
+    Warning,
+    Critical,
+}
+
+/// Warning and critical temperatures of one kind of component
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct ThermalThresholds {
+    pub warning_threshold: f32,
+    pub critical_threshold: f32,
+}
+
+impl ThermalThresholds {
+    /// Level of a device that was at `previous` on the last poll
+    pub(crate) fn level(self, previous: ThermalLevel, temperature: f32) -> ThermalLevel {
+        let critical = if previous == ThermalLevel::Critical {
+            self.critical_threshold - THERMAL_ALERT_HYSTERESIS
+        } else {
+            self.critical_threshold
+        };
+        let warning = if previous >= ThermalLevel::Warning {
+            self.warning_threshold - THERMAL_ALERT_HYSTERESIS
+        } else {
+            self.warning_threshold
+        };
+        if temperature >= critical {
+            ThermalLevel::Critical
+        } else if temperature >= warning {
+            ThermalLevel::Warning
+        } else {
+            ThermalLevel::Normal
         }
     }
 
-    fn detect_current_os() -> CurrentOperatingSystem {
-        if cfg!(target_os = "windows") {
-            CurrentOperatingSystem::Windows
-        } else if cfg!(target_os = "linux") {
-            CurrentOperatingSystem::Linux
-        } else if cfg!(target_os = "macos") {
-            CurrentOperatingSystem::MacOS
-        } else {
-            panic!("Unsupported OS");
+    fn map_temperatures(self, convert: impl Fn(f32) -> f32) -> Self {
+        ThermalThresholds {
+            warning_threshold: convert(self.warning_threshold),
+            critical_threshold: convert(self.critical_threshold),
         }
     }
+}
 
-    pub fn read_hardware_parameters(&mut self) -> HardwareStatus {
-        // USED FOR DEBUGGING
-        // println!("Reading hardware parameters for {}", self.current_implementation.get_implementation_name());
-        // self.current_implementation.log_all_components();
-        let cpu = Some(
-            self.current_implementation
-                .read_cpu_parameters(self.cpu.clone()),
-        );
-        let gpu = self
-            .current_implementation
-            .read_gpu_parameters(self.gpu.clone());
+/// A device that rose to a higher thermal level during the poll
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ThermalAlert {
+    pub label: String,
+    pub component: ComponentKind,
+    pub level: ThermalLevel,
+    pub temperature: f32,
+}
 
-        self.cpu = cpu.clone();
-        self.gpu = gpu.clone();
+/// Unit of the reported temperatures, readings are always tracked in Celsius internally
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TemperatureUnit {
+    #[default]
+    Celsius,
+    Kelvin,
+    Fahrenheit,
+}
+
+impl TemperatureUnit {
+    pub fn from_celsius(self, celsius: f32) -> f32 {
+        match self {
+            TemperatureUnit::Celsius => celsius,
+            TemperatureUnit::Kelvin => celsius + 273.15,
+            TemperatureUnit::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+        }
+    }
 
-        HardwareStatus { cpu, gpu }
+    pub fn to_celsius(self, temperature: f32) -> f32 {
+        match self {
+            TemperatureUnit::Celsius => temperature,
+            TemperatureUnit::Kelvin => temperature - 273.15,
+            TemperatureUnit::Fahrenheit => (temperature - 32.0) * 5.0 / 9.0,
+        }
     }
 
-    pub fn read_gpu_devices(&mut self) -> Vec<GpuStatus> {
-        let gpu_dev = self.current_implementation.read_gpu_devices();
-        self.gpu_devices = gpu_dev.clone();
-        gpu_dev
+    pub fn symbol(self) -> &'static str {
+        match self {
+            TemperatureUnit::Celsius => "°C",
+            TemperatureUnit::Kelvin => "K",
+            TemperatureUnit::Fahrenheit => "°F",
+        }
     }
-    pub fn load_status_file(&mut self, config_path: PathBuf) -> Result<(), anyhow::Error> {
-        match self.current_implementation.load_status_file(config_path) {
-            Ok(_) => {
-                trace!(target: LOG_TARGET, "Gpu status file loaded successfully");
-                Ok(())
+}
+
+/// Writes `<name> <usage>% <temperature><unit>`, the segment shared by the one-line summaries
+fn write_device_summary(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    device: &HardwareParameters,
+    unit: TemperatureUnit,
+) -> fmt::Result {
+    write!(
+        f,
+        "{} {:.0}% {:.0}{}",
+        name,
+        device.usage_percentage,
+        device.current_temperature,
+        unit.symbol()
+    )
+}
+
+/// One-line summary of the device, e.g. `NVIDIA GeForce RTX 3080 99% 68°C`.
+///
+/// The readings do not carry their unit, so they are always labelled Celsius. A device taken
+/// from a `HardwareStatus` in another unit reads wrong here, format the whole status instead.
+impl fmt::Display for HardwareParameters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_device_summary(f, &self.label, self, TemperatureUnit::Celsius)
+    }
+}
+
+/// One-line summary such as `CPU 45% 62°C | GPU0 99% 68°C`, the CPU is left out when unread
+impl fmt::Display for HardwareStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let devices = self.cpu.iter().map(|cpu| ("CPU".to_string(), cpu)).chain(
+            self.gpu
+                .iter()
+                .enumerate()
+                .map(|(i, gpu)| (format!("GPU{}", i), gpu)),
+        );
+        for (i, (name, device)) in devices.enumerate() {
+            if i > 0 {
+                f.write_str(" | ")?;
             }
-            Err(e) => Err(anyhow!("Fail to load gpu status file: {:?}", e)),
+            write_device_summary(f, &name, device, self.temperature_unit)?;
         }
+        Ok(())
     }
 }
 
-struct WindowsHardwareMonitor {
-    nvml: Option<Nvml>,
-    gpu_status_file: Option<PathBuf>,
+impl HardwareParameters {
+    /// Converts the Celsius readings of the device to the given unit
+    pub fn with_temperature_unit(self, unit: TemperatureUnit) -> Self {
+        self.map_temperatures(|t| unit.from_celsius(t))
+    }
+
+    /// Converts readings reported in the given unit back to Celsius
+    pub fn into_celsius(self, unit: TemperatureUnit) -> Self {
+        self.map_temperatures(|t| unit.to_celsius(t))
+    }
+
+    /// Replaces a raw sensor label with its friendly name, if one is configured
+    fn with_display_name(mut self, display_names: &HashMap<String, String>) -> Self {
+        if let Some(display_name) = display_names.get(&self.label) {
+            self.label.clone_from(display_name);
+        }
+        self
+    }
+
+    /// Forgets the session extremes, so the next reading starts them over
+    pub(crate) fn reset_peak_temperatures(&mut self) {
+        self.max_temperature = 0.0;
+        self.min_temperature = unset_min_temperature();
+        self.max_power_watts = None;
+        for socket in &mut self.sockets {
+            socket.max_temperature = 0.0;
+        }
+    }
+
+    fn map_temperatures(mut self, convert: impl Fn(f32) -> f32) -> Self {
+        self.current_temperature = convert(self.current_temperature);
+        self.max_temperature = convert(self.max_temperature);
+        self.average_temperature = convert(self.average_temperature);
+        // An unset minimum holds no reading, and scaling it to Fahrenheit would overflow
+        if self.min_temperature < unset_min_temperature() {
+            self.min_temperature = convert(self.min_temperature);
+        }
+        self.cpu_tjmax = self.cpu_tjmax.map(&convert);
+        self.package_temperature = self.package_temperature.map(&convert);
+        self.ccd_average_temperature = self.ccd_average_temperature.map(&convert);
+        self.ccd_max_temperature = self.ccd_max_temperature.map(&convert);
+        self.core_temperatures = self.core_temperatures.into_iter().map(&convert).collect();
+        self.memory_temperature = self.memory_temperature.map(&convert);
+        self.slowdown_temperature = self.slowdown_temperature.map(&convert);
+        for socket in &mut self.sockets {
+            socket.current_temperature = convert(socket.current_temperature);
+            socket.max_temperature = convert(socket.max_temperature);
+        }
+        self
+    }
 }
-impl HardwareMonitorImpl for WindowsHardwareMonitor {
-    fn _get_implementation_name(&self) -> String {
-        "Windows".to_string()
+
+/// What GPU reads report for a sensor that errors or a device that times out
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FailurePolicy {
+    /// Keep the last good value, for smooth graphs
+    #[default]
+    HoldLast,
+    /// Report zero or `None`, so failures show up as such
+    ResetToDefault,
+}
+
+impl FailurePolicy {
+    fn fallback<T: Default>(self, last: Option<T>) -> T {
+        match self {
+            FailurePolicy::HoldLast => last.unwrap_or_default(),
+            FailurePolicy::ResetToDefault => T::default(),
+        }
     }
 
-    fn _log_all_components(&self) {
-        let components = Components::new_with_refreshed_list();
-        for component in components.deref() {
-            println!(
-                "Component: {} Temperature: {}",
-                component.label(),
-                component.temperature()
-            );
+    /// Stand-in for a device that could not be read at all, the session maximum is always kept
+    fn failed_device(self, last: Option<&HardwareParameters>) -> Option<HardwareParameters> {
+        let last = last?;
+        match self {
+            FailurePolicy::HoldLast => Some(last.clone()),
+            FailurePolicy::ResetToDefault => Some(HardwareParameters {
+                label: last.label.clone(),
+                uuid: last.uuid.clone(),
+                max_temperature: last.max_temperature,
+                min_temperature: last.min_temperature,
+                is_integrated: last.is_integrated,
+                supported_sensors: last.supported_sensors,
+                ..Default::default()
+            }),
         }
     }
+}
 
-    fn read_cpu_parameters(
-        &self,
-        current_parameters: Option<HardwareParameters>,
-    ) -> HardwareParameters {
-        let mut system =
-            System::new_with_specifics(RefreshKind::new().with_cpu(CpuRefreshKind::everything()));
-        let components = Components::new_with_refreshed_list();
-        let cpu_components: Vec<&Component> = components
-            .deref()
+/// Distinguishes sensors reading zero from sensors that cannot be read at all, e.g. in a VM
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Availability {
+    #[default]
+    Available,
+    Unavailable,
+}
+
+/// Fields of a device that changed since the last emitted status, `None` when unchanged
+#[derive(Clone, Debug, Serialize)]
+pub struct HardwareParametersDelta {
+    pub label: String,
+    pub uuid: Option<String>,
+    pub usage_percentage: Option<f32>,
+    pub current_temperature: Option<f32>,
+    pub max_temperature: Option<f32>,
+}
+
+impl HardwareParametersDelta {
+    fn with_temperature_unit(mut self, unit: TemperatureUnit) -> Self {
+        self.current_temperature = self.current_temperature.map(|t| unit.from_celsius(t));
+        self.max_temperature = self.max_temperature.map(|t| unit.from_celsius(t));
+        self
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct HardwareStatusDelta {
+    pub cpu: Option<HardwareParametersDelta>,
+    pub gpu: Vec<HardwareParametersDelta>,
+    pub gpu_count: usize,
+}
+
+/// Changes smaller than this (in °C or percentage points) are left out of status deltas
+const DEFAULT_STATUS_DELTA_EPSILON: f32 = 0.5;
+
+/// Upper bound of the default number of GPUs read at the same time
+const MAX_DEFAULT_GPU_READ_CONCURRENCY: usize = 8;
+
+fn default_gpu_read_concurrency() -> usize {
+    std::thread::available_parallelism()
+        .map(|cores| cores.get())
+        .unwrap_or(1)
+        .min(MAX_DEFAULT_GPU_READ_CONCURRENCY)
+}
+
+/// Time between the two CPU refreshes that usage is computed from. sysinfo's own minimum
+/// differs between its versions and platforms, so it can only raise this floor.
+const CPU_USAGE_DIFF_INTERVAL: Duration = Duration::from_millis(200);
+
+pub(crate) fn cpu_usage_diff_interval() -> Duration {
+    CPU_USAGE_DIFF_INTERVAL.max(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL)
+}
+
+/// Rated TDP of common mining CPUs, for platforms that don't expose a power limit.
+/// More specific models come first, e.g. the X3D parts before their X siblings.
+const CPU_TDP_WATTS: [(&str, f32); 12] = [
+    ("Ryzen 9 7950X3D", 120.0),
+    ("Ryzen 9 7950X", 170.0),
+    ("Ryzen 9 7900X", 170.0),
+    ("Ryzen 7 7800X3D", 120.0),
+    ("Ryzen 9 5950X", 105.0),
+    ("Ryzen 9 5900X", 105.0),
+    ("Ryzen 7 5800X", 105.0),
+    ("Ryzen 5 5600X", 65.0),
+    ("i9-13900KS", 150.0),
+    ("i9-14900K", 125.0),
+    ("i9-13900K", 125.0),
+    ("i9-12900K", 125.0),
+];
+
+fn lookup_cpu_tdp_watts(label: &str) -> Option<f32> {
+    CPU_TDP_WATTS
+        .iter()
+        .find(|(model, _)| label.contains(model))
+        .map(|(_, tdp)| *tdp)
+}
+
+/// Reported when sysinfo lists no CPUs, as happens inside some VMs and sandboxes
+const UNKNOWN_CPU_LABEL: &str = "Unknown CPU";
+
+pub(crate) fn cpu_label(cpus: &[Cpu]) -> String {
+    cpus.first().map_or_else(
+        || UNKNOWN_CPU_LABEL.to_string(),
+        |cpu| cpu.brand().to_string(),
+    )
+}
+
+fn sysinfo_cpu_frequency_mhz(system: &System) -> Option<u32> {
+    system
+        .cpus()
+        .iter()
+        .map(|cpu| cpu.frequency())
+        .max()
+        .filter(|frequency| *frequency > 0)
+        .and_then(|frequency| u32::try_from(frequency).ok())
+}
+
+/// Clocks dropping under load point at thermal throttling rather than high usage
+fn sysinfo_core_frequencies_mhz(system: &System) -> Vec<u32> {
+    let frequencies: Vec<u32> = system
+        .cpus()
+        .iter()
+        .map(|cpu| u32::try_from(cpu.frequency()).unwrap_or(u32::MAX))
+        .collect();
+    if frequencies.iter().all(|frequency| *frequency == 0) {
+        vec![]
+    } else {
+        frequencies
+    }
+}
+
+fn average(values: &[f32]) -> Option<f32> {
+    if values.is_empty() {
+        None
+    } else {
+        Some(values.iter().sum::<f32>() / values.len() as f32)
+    }
+}
+
+/// Mean and maximum of the per-CCD temperatures, `None` when the CPU has no CCD sensors
+pub(crate) fn ccd_temperature_summary(temperatures: &[f32]) -> Option<(f32, f32)> {
+    let max = temperatures.iter().copied().reduce(f32::max)?;
+    Some((average(temperatures)?, max))
+}
+
+/// Average CPU temperature, 0.0 when no sensor matched instead of NaN
+pub(crate) fn average_cpu_temperature(temperatures: &[f32]) -> f32 {
+    average(temperatures).unwrap_or_default()
+}
+
+/// Friendly names of common cryptic sensor labels, overridable with `set_sensor_display_names`
+const DEFAULT_SENSOR_DISPLAY_NAMES: [(&str, &str); 6] = [
+    ("k10temp Tctl", "CPU (Tctl)"),
+    ("k10temp Tdie", "CPU (Tdie)"),
+    ("k10temp Tccd1", "CPU CCD 1"),
+    ("coretemp Package id 0", "CPU Package"),
+    ("acpitz temp1", "ACPI Thermal Zone"),
+    ("nvme Composite", "NVMe SSD"),
+];
+
+fn default_sensor_display_names() -> HashMap<String, String> {
+    DEFAULT_SENSOR_DISPLAY_NAMES
+        .iter()
+        .map(|(label, display_name)| (label.to_string(), display_name.to_string()))
+        .collect()
+}
+
+/// Thermal limit assumed for devices that do not report their own
+pub const DEFAULT_REFERENCE_MAX_TEMPERATURE: f32 = 100.0;
+
+/// Manufacturer of a GPU, reported by its backend or guessed from its name
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GpuVendor {
+    Nvidia,
+    Amd,
+    Intel,
+    Apple,
+    #[default]
+    Unknown,
+}
+
+impl GpuVendor {
+    pub fn from_label(label: &str) -> Self {
+        let label = label.to_lowercase();
+        if ["nvidia", "geforce", "quadro", "tesla"]
             .iter()
-            .filter(|c| c.label().contains("Cpu"))
+            .any(|name| label.contains(name))
+        {
+            GpuVendor::Nvidia
+        } else if label.contains("amd") || label.contains("radeon") {
+            GpuVendor::Amd
+        } else if label.contains("apple") {
+            GpuVendor::Apple
+        } else if label.contains("intel") || is_integrated_gpu(&label) {
+            GpuVendor::Intel
+        } else {
+            GpuVendor::Unknown
+        }
+    }
+}
+
+impl HardwareStatus {
+    /// Converts every device reading from the status' unit back to Celsius
+    fn into_celsius(self) -> Self {
+        let unit = self.temperature_unit;
+        let into_celsius = |devices: Vec<HardwareParameters>| -> Vec<HardwareParameters> {
+            devices
+                .into_iter()
+                .map(|device| device.into_celsius(unit))
+                .collect()
+        };
+        HardwareStatus {
+            cpu: self.cpu.map(|cpu| cpu.into_celsius(unit)),
+            gpu: into_celsius(self.gpu),
+            motherboard: into_celsius(self.motherboard),
+            disks: into_celsius(self.disks),
+            alerts: self
+                .alerts
+                .into_iter()
+                .map(|alert| ThermalAlert {
+                    temperature: unit.to_celsius(alert.temperature),
+                    ..alert
+                })
+                .collect(),
+            temperature_unit: TemperatureUnit::Celsius,
+            ..self
+        }
+    }
+
+    /// Combines the statuses of several rigs, rigs without an id are named by their position
+    pub fn merge(statuses: &[HardwareStatus]) -> AggregatedStatus {
+        let rigs: Vec<RigSummary> = statuses
+            .iter()
+            .enumerate()
+            .map(|(i, status)| status.rig_summary(i))
             .collect();
 
-        let avarage_temperature = cpu_components.iter().map(|c| c.temperature()).sum::<f32>()
-            / cpu_components.len() as f32;
+        let gpu_temperatures: Vec<f32> = statuses
+            .iter()
+            .flat_map(|status| {
+                status
+                    .gpu
+                    .iter()
+                    .map(|gpu| status.temperature_unit.to_celsius(gpu.current_temperature))
+            })
+            .collect();
 
-        // Wait a bit because CPU usage is based on diff.
-        std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
-        system.refresh_cpu_all();
+        AggregatedStatus {
+            rig_count: rigs.len(),
+            gpu_count: gpu_temperatures.len(),
+            average_gpu_temperature: average(&gpu_temperatures),
+            max_temperature: rigs
+                .iter()
+                .filter_map(|rig| rig.max_temperature)
+                .reduce(f32::max),
+            total_power_draw_watts: rigs
+                .iter()
+                .filter_map(|rig| rig.total_power_draw_watts)
+                .reduce(|a, b| a + b),
+            rigs,
+        }
+    }
 
-        let usage = system.global_cpu_usage();
-        let label: String = match system.cpus().first() {
-            Some(cpu) => cpu.brand().to_string(),
-            None => {
-                warn!("Failed to get CPU brand");
-                "N/A".to_string()
-            }
-        };
+    fn rig_summary(&self, index: usize) -> RigSummary {
+        let unit = self.temperature_unit;
+        let gpu_temperatures: Vec<f32> = self
+            .gpu
+            .iter()
+            .map(|gpu| unit.to_celsius(gpu.current_temperature))
+            .collect();
+        let cpu = self.cpu.clone().map(|cpu| cpu.into_celsius(unit));
 
-        match current_parameters {
-            Some(current_parameters) => HardwareParameters {
-                label,
-                usage_percentage: usage,
-                current_temperature: avarage_temperature,
-                max_temperature: current_parameters.max_temperature.max(avarage_temperature),
-            },
-            None => HardwareParameters {
-                label,
-                usage_percentage: usage,
-                current_temperature: avarage_temperature,
-                max_temperature: avarage_temperature,
-            },
+        RigSummary {
+            rig_id: self
+                .rig_id
+                .clone()
+                .unwrap_or_else(|| format!("rig {}", index + 1)),
+            gpu_count: self.gpu.len(),
+            average_gpu_temperature: average(&gpu_temperatures),
+            max_temperature: cpu
+                .iter()
+                .map(|cpu| cpu.current_temperature)
+                .chain(gpu_temperatures.iter().copied())
+                .reduce(f32::max),
+            total_power_draw_watts: self
+                .gpu
+                .iter()
+                .filter_map(|gpu| gpu.power_draw_watts)
+                .reduce(|a, b| a + b),
+            implausible_readings: self.implausible_readings,
+            cpu,
         }
     }
-    fn read_gpu_parameters(
+
+    /// Folds the GPUs of each vendor into one entry holding their average usage and
+    /// temperature, the highest max temperature and the total power draw
+    pub fn by_vendor(&self) -> HashMap<GpuVendor, HardwareParameters> {
+        let mut groups: HashMap<GpuVendor, Vec<&HardwareParameters>> = HashMap::new();
+        for gpu in &self.gpu {
+            let vendor = match gpu.vendor {
+                GpuVendor::Unknown => GpuVendor::from_label(&gpu.label),
+                vendor => vendor,
+            };
+            groups.entry(vendor).or_default().push(gpu);
+        }
+
+        groups
+            .into_iter()
+            .map(|(vendor, gpus)| {
+                let count = gpus.len() as f32;
+                let rollup = HardwareParameters {
+                    label: format!("{:?}", vendor),
+                    usage_percentage: gpus.iter().map(|g| g.usage_percentage).sum::<f32>() / count,
+                    current_temperature: gpus.iter().map(|g| g.current_temperature).sum::<f32>()
+                        / count,
+                    max_temperature: gpus
+                        .iter()
+                        .map(|g| g.max_temperature)
+                        .fold(f32::MIN, f32::max),
+                    min_temperature: gpus
+                        .iter()
+                        .map(|g| g.min_temperature)
+                        .fold(f32::MAX, f32::min),
+                    uncorrected_ecc_errors: gpus
+                        .iter()
+                        .filter_map(|g| g.uncorrected_ecc_errors)
+                        .reduce(|a, b| a + b),
+                    is_integrated: gpus.iter().all(|g| g.is_integrated),
+                    power_draw_watts: gpus
+                        .iter()
+                        .filter_map(|g| g.power_draw_watts)
+                        .reduce(|a, b| a + b),
+                    ..Default::default()
+                };
+                (vendor, rollup)
+            })
+            .collect()
+    }
+
+    /// Each device's temperature as a percentage of its thermal limit (Tjmax or GPU slowdown)
+    pub fn thermal_percentages(&self) -> Vec<(String, f32)> {
+        self.thermal_percentages_with_reference(DEFAULT_REFERENCE_MAX_TEMPERATURE)
+    }
+
+    pub fn thermal_percentages_with_reference(
         &self,
-        current_parameters: Vec<HardwareParameters>,
-    ) -> Vec<HardwareParameters> {
-        let mut gpu_devices = vec![];
-        let nvml = match &self.nvml {
-            Some(nvml) => nvml,
-            None => {
-                return gpu_devices;
-            }
+        reference_max_temperature: f32,
+    ) -> Vec<(String, f32)> {
+        self.cpu
+            .iter()
+            .chain(self.gpu.iter())
+            .map(|device| {
+                let unit = self.temperature_unit;
+                let limit = device
+                    .cpu_tjmax
+                    .or(device.slowdown_temperature)
+                    .map_or(reference_max_temperature, |limit| unit.to_celsius(limit));
+                (
+                    device.label.clone(),
+                    unit.to_celsius(device.current_temperature) / limit * 100.0,
+                )
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum HardwareMonitorError {
+    #[error("NVML call failed: {0}")]
+    Nvml(#[from] NvmlError),
+    #[error("Sensor read task failed: {0}")]
+    ReadTask(String),
+    #[error("The replayed row has no CPU reading")]
+    ReplayMissingCpu,
+    #[error("The replay reached its last row")]
+    ReplayFinished,
+}
+
+/// Raw readings of one poll, taken off the async runtime
+struct SensorReadings {
+    cpu: Result<HardwareParameters, HardwareMonitorError>,
+    gpu: Result<Vec<HardwareParameters>, HardwareMonitorError>,
+    motherboard: Vec<HardwareParameters>,
+    disks: Vec<HardwareParameters>,
+    memory: Option<MemoryParameters>,
+    power: Option<PowerStatus>,
+    has_gpu_sensors: bool,
+}
+
+impl SensorReadings {
+    fn read(
+        implementation: &dyn HardwareMonitorImpl,
+        cpu: Option<HardwareParameters>,
+        gpu: Vec<HardwareParameters>,
+        motherboard: Vec<HardwareParameters>,
+        disks: Vec<HardwareParameters>,
+    ) -> Self {
+        implementation.start_poll();
+        let gpu = implementation.read_gpu_parameters(gpu);
+        // Backends report only their primary GPU library, a card read from sysfs
+        // or nvidia-smi still means GPU sensors are available
+        let has_gpu_sensors =
+            implementation.has_gpu_sensors() || gpu.as_ref().map_or(false, |gpus| !gpus.is_empty());
+        let readings = SensorReadings {
+            cpu: implementation.read_cpu_parameters(cpu),
+            gpu,
+            motherboard: implementation.read_motherboard_parameters(motherboard),
+            disks: implementation.read_disk_parameters(disks),
+            memory: implementation.read_memory_parameters(),
+            power: implementation.read_power_status(),
+            has_gpu_sensors,
         };
+        // Only once every section is read, so all of them come from the same replayed row
+        implementation.finish_poll();
+        readings
+    }
+}
 
-        let num_of_devices = nvml.device_count().unwrap_or_else(|e| {
-            println!("Failed to get number of GPU devices: {}", e);
-            0
-        });
-        for i in 0..num_of_devices {
-            let current_gpu = match nvml.device_by_index(i) {
-                Ok(device) => device,
-                Err(e) => {
-                    println!("Failed to get main GPU: {}", e);
-                    continue; // skip to the next iteration
-                }
+/// Backend that reads the sensors of one platform.
+///
+/// The `current_parameters` passed to the read functions are the readings returned
+/// by the previous poll, or `None`/empty on the first one. Implementations use them to
+/// carry `max_temperature` over, so the maximum is the highest temperature seen this session.
+pub trait HardwareMonitorImpl: Send + Sync + 'static {
+    fn get_implementation_kind(&self) -> ImplementationKind;
+    /// Reads the CPU, may block for the interval needed to measure its usage
+    fn read_cpu_parameters(
+        &self,
+        current_parameters: Option<HardwareParameters>,
+    ) -> Result<HardwareParameters, HardwareMonitorError>;
+    /// Reads every GPU, `current_parameters` holds the previous readings of all of them.
+    /// A device that fails to read is skipped or held according to the failure policy,
+    /// an error means no GPU could be enumerated at all.
+    fn read_gpu_parameters(
+        &self,
+        current_parameters: Vec<HardwareParameters>,
+    ) -> Result<Vec<HardwareParameters>, HardwareMonitorError>;
+    /// Reads a single GPU by uuid, `None` if the device is unknown or not addressable by uuid
+    fn read_gpu_device_parameters(
+        &self,
+        uuid: &str,
+        current_parameters: Option<HardwareParameters>,
+    ) -> Option<HardwareParameters>;
+    fn read_motherboard_parameters(
+        &self,
+        current_parameters: Vec<HardwareParameters>,
+    ) -> Vec<HardwareParameters>;
+    /// Drive temperature sensors, none by default as most platforms do not list them
+    fn read_disk_parameters(
+        &self,
+        _current_parameters: Vec<HardwareParameters>,
+    ) -> Vec<HardwareParameters> {
+        vec![]
+    }
+    fn read_memory_parameters(&self) -> Option<MemoryParameters>;
+    /// GPU devices listed in the gpu status file written by the GPU miner
+    fn read_gpu_devices(&self) -> Vec<GpuStatus>;
+    /// Whether GPU temperature and usage can be read, rather than only listing the devices
+    fn has_gpu_sensors(&self) -> bool;
+    /// Settings take `&self` so they apply without waiting for a read in flight
+    fn set_gpu_read_concurrency(&self, concurrency: usize);
+    fn gpu_read_concurrency(&self) -> Option<usize>;
+    fn set_failure_policy(&self, policy: FailurePolicy);
+    /// Reads NVIDIA GPUs through the nvidia-smi CLI when NVML cannot be loaded
+    fn set_nvidia_smi_fallback(&self, _enabled: bool) {
+        // Only the Linux backend knows how to fall back to nvidia-smi
+    }
+    fn gpu_backend_status(&self) -> GpuBackendStatus {
+        // Only the Windows and Linux backends read GPUs through NVML
+        GpuBackendStatus::default()
+    }
+    /// XID errors reported by NVML this session, by GPU uuid
+    fn gpu_xid_errors(&self) -> HashMap<String, XidErrors> {
+        HashMap::new()
+    }
+    /// Called before any section of a poll is read
+    fn start_poll(&self) {
+        // Only backends that keep sensors between polls have to refresh them
+    }
+    /// Called after every section of a poll has been read
+    fn finish_poll(&self) {
+        // Only backends that keep readings for a single poll have to drop them
+    }
+    /// AC or battery power of the machine, read the same way by every backend
+    fn read_power_status(&self) -> Option<PowerStatus> {
+        power_supply::read_power_status()
+    }
+    fn load_status_file(&self, config_path: PathBuf) -> Result<(), anyhow::Error>;
+    fn _log_all_components(&self);
+}
+
+/// Whether NVIDIA GPUs can be read, so the UI can explain why no GPU is listed
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct GpuBackendStatus {
+    pub nvml_available: bool,
+    /// Why NVML could not be initialized, e.g. a missing or outdated NVIDIA driver
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nvml_error: Option<String>,
+}
+
+pub struct HardwareMonitor {
+    /// Shared with the blocking read task, so a poll does not hold the monitor lock while sampling.
+    /// Switching backends swaps the `Arc`, a read in flight keeps the backend it started with.
+    current_implementation: Arc<dyn HardwareMonitorImpl>,
+    cpu: Option<HardwareParameters>,
+    gpu: Vec<HardwareParameters>,
+    motherboard: Vec<HardwareParameters>,
+    disks: Vec<HardwareParameters>,
+    gpu_devices: Vec<GpuStatus>,
+    device_priorities: HashMap<String, Duration>,
+    on_ecc_error_detected: Option<EccErrorCallback>,
+    on_new_peak: Option<NewPeakCallback>,
+    include_integrated_gpus: bool,
+    min_usage_filter: Option<f32>,
+    last_emitted_status: Option<HardwareStatus>,
+    status_delta_epsilon: f32,
+    temperature_unit: TemperatureUnit,
+    plausibility_thresholds: Option<PlausibilityThresholds>,
+    gpu_first_seen: HashMap<String, Instant>,
+    vanished_gpus: HashSet<String>,
+    gpu_resets: HashMap<String, u32>,
+    failure_policy: FailurePolicy,
+    rig_id: Option<String>,
+    sensor_display_names: HashMap<String, String>,
+    history: HashMap<String, VecDeque<HistorySample>>,
+    history_memory_budget: usize,
+    gpu_status_history: Vec<GpuStatusTransition>,
+    nvidia_smi_fallback: bool,
+    temperature_alerts: HashMap<String, f32>,
+    alerting_devices: HashSet<String>,
+    alerts_file: Option<PathBuf>,
+    on_temperature_alert: Option<TemperatureAlertCallback>,
+    thermal_thresholds: HashMap<ComponentKind, ThermalThresholds>,
+    thermal_levels: HashMap<String, ThermalLevel>,
+    polling_sender: Option<broadcast::Sender<HardwareStatus>>,
+    gpu_devices_sender: Option<broadcast::Sender<Vec<GpuStatus>>>,
+    temperature_average_window: usize,
+    temperature_windows: HashMap<String, VecDeque<f32>>,
+    csv_logger: Option<CsvLogWriter>,
+    /// Temperature in Celsius the GPUs are warned about before they reach it
+    thermal_limit: Option<f32>,
+    approaching_limit_devices: HashSet<String>,
+    /// Sample time of the last throttled read and the throttle duration so far, per GPU
+    throttling_gpus: HashMap<String, (SystemTime, u64)>,
+    min_refresh_interval: Duration,
+    last_read: Option<(Instant, HardwareStatus)>,
+}
+
+/// Statuses a slow subscriber of the polling task may fall behind before it skips the oldest
+const POLLING_CHANNEL_CAPACITY: usize = 16;
+
+/// Device lists a slow subscriber of the status file watcher may fall behind
+const GPU_DEVICES_CHANNEL_CAPACITY: usize = 4;
+
+/// Readings a slow subscriber of a prioritized device may fall behind
+const PRIORITIZED_DEVICE_CHANNEL_CAPACITY: usize = 16;
+
+/// Saves of the gpu status file within this window are reloaded once
+const GPU_STATUS_WATCH_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Number of GPU status transitions kept for diagnostics
+const MAX_GPU_STATUS_HISTORY: usize = 256;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct GpuStatusTransition {
+    pub timestamp: SystemTime,
+    pub device_name: String,
+    pub was_available: bool,
+    pub is_available: bool,
+}
+
+/// Reads requested sooner than this after the previous one get its status back instead,
+/// zero so every read touches the sensors until an interval is configured
+const DEFAULT_MIN_REFRESH_INTERVAL: Duration = Duration::ZERO;
+
+/// Readings averaged into `average_temperature` unless configured otherwise, 1 disables smoothing
+const DEFAULT_TEMPERATURE_AVERAGE_WINDOW: usize = 1;
+
+/// Adds a reading to a device's window and returns the mean of the readings it holds
+pub(crate) fn rolling_average(window: &mut VecDeque<f32>, length: usize, temperature: f32) -> f32 {
+    window.push_back(temperature);
+    while window.len() > length.max(1) {
+        window.pop_front();
+    }
+    window.iter().sum::<f32>() / window.len() as f32
+}
+
+/// Memory all device histories may take together unless configured otherwise
+const DEFAULT_HISTORY_MEMORY_BUDGET: usize = 1024 * 1024;
+
+/// One poll of a device, temperatures in Celsius
+#[derive(Clone, Debug, Serialize)]
+pub struct HistorySample {
+    pub sample_time: SystemTime,
+    pub usage_percentage: f32,
+    pub temperature: f32,
+}
+
+/// Snapshot of every active monitor setting, attached to diagnostics
+#[derive(Clone, Debug, Serialize)]
+pub struct HardwareMonitorConfig {
+    pub implementation: ImplementationKind,
+    pub include_integrated_gpus: bool,
+    pub min_usage_filter: Option<f32>,
+    pub status_delta_epsilon: f32,
+    pub temperature_unit: TemperatureUnit,
+    pub plausibility_thresholds: Option<PlausibilityThresholds>,
+    pub gpu_read_concurrency: Option<usize>,
+    pub failure_policy: FailurePolicy,
+    pub device_priorities_ms: HashMap<String, u128>,
+    pub history_memory_budget: usize,
+    pub nvidia_smi_fallback: bool,
+    pub temperature_average_window: usize,
+    pub min_refresh_interval_ms: u128,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct GpuUptime {
+    pub uuid: String,
+    pub label: String,
+    pub uptime_secs: u64,
+    pub resets: u32,
+    pub xid_errors: u32,
+    pub last_xid: Option<u64>,
+}
+
+/// Critical XID errors NVML reported for a GPU, driver-visible faults that often precede a reset
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct XidErrors {
+    pub count: u32,
+    pub last_xid: Option<u64>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct GpuDeviceParametersEventPayload {
+    pub uuid: String,
+    pub parameters: HardwareParameters,
+}
+
+impl HardwareMonitor {
+    pub fn new() -> Self {
+        HardwareMonitor::with_implementation(HardwareMonitor::create_os_implementation())
+    }
+
+    /// A monitor reading through the given backend instead of the one of the current OS
+    pub fn with_implementation(implementation: Box<dyn HardwareMonitorImpl>) -> Self {
+        HardwareMonitor {
+            current_implementation: Arc::from(implementation),
+            cpu: None,
+            gpu: vec![],
+            motherboard: vec![],
+            disks: vec![],
+            gpu_devices: vec![],
+            device_priorities: HashMap::new(),
+            on_ecc_error_detected: None,
+            on_new_peak: None,
+            include_integrated_gpus: true,
+            min_usage_filter: None,
+            last_emitted_status: None,
+            status_delta_epsilon: DEFAULT_STATUS_DELTA_EPSILON,
+            temperature_unit: TemperatureUnit::Celsius,
+            plausibility_thresholds: Some(PlausibilityThresholds::default()),
+            gpu_first_seen: HashMap::new(),
+            vanished_gpus: HashSet::new(),
+            gpu_resets: HashMap::new(),
+            failure_policy: FailurePolicy::default(),
+            rig_id: None,
+            sensor_display_names: default_sensor_display_names(),
+            history: HashMap::new(),
+            history_memory_budget: DEFAULT_HISTORY_MEMORY_BUDGET,
+            gpu_status_history: vec![],
+            nvidia_smi_fallback: false,
+            temperature_alerts: HashMap::new(),
+            alerting_devices: HashSet::new(),
+            alerts_file: None,
+            on_temperature_alert: None,
+            thermal_thresholds: HashMap::new(),
+            thermal_levels: HashMap::new(),
+            temperature_average_window: DEFAULT_TEMPERATURE_AVERAGE_WINDOW,
+            temperature_windows: HashMap::new(),
+            csv_logger: None,
+            thermal_limit: None,
+            approaching_limit_devices: HashSet::new(),
+            throttling_gpus: HashMap::new(),
+            min_refresh_interval: DEFAULT_MIN_REFRESH_INTERVAL,
+            last_read: None,
+            polling_sender: None,
+            gpu_devices_sender: None,
+        }
+    }
+
+    pub fn current() -> &'static RwLock<HardwareMonitor> {
+        &INSTANCE
+    }
+
+    fn create_os_implementation() -> Box<dyn HardwareMonitorImpl> {
+        match HardwareMonitor::detect_current_os() {
+            CurrentOperatingSystem::Windows => Box::new(WindowsHardwareMonitor {
+                sysinfo: SysinfoCache::new(),
+                nvml_reader: NvmlGpuReader::new(),
+                libre_hardware_monitor: LibreHardwareMonitorReader::default(),
+                gpu_status_file: Mutex::new(None),
+            }),
+            CurrentOperatingSystem::Linux => Box::new(LinuxHardwareMonitor {
+                sysinfo: SysinfoCache::new(),
+                nvml_reader: NvmlGpuReader::new(),
+                gpu_status_file: Mutex::new(None),
+                nvidia_smi_fallback: AtomicBool::new(false),
+            }),
+            CurrentOperatingSystem::MacOS => Box::new(MacOSHardwareMonitor {
+                sysinfo: SysinfoCache::new(),
+                gpu_status_file: Mutex::new(None),
+            }),
+            CurrentOperatingSystem::Other => Box::new(FallbackHardwareMonitor {
+                sysinfo: SysinfoCache::new(),
+            }),
+        }
+    }
+
+    /// Replaces live readings with the rows of a recorded hardware log, exported JSON statuses
+    /// or a `.csv` file of the CSV logger, one row per `read_hardware_parameters` call
+    pub fn start_replay(&mut self, file: PathBuf, loop_replay: bool) -> Result<(), anyhow::Error> {
+        let replay = ReplayHardwareMonitor::load(file, loop_replay)?;
+        info!(target: LOG_TARGET, "Replaying {} recorded hardware status rows", replay.rows.len());
+        self.set_implementation(Box::new(replay));
+        Ok(())
+    }
+
+    pub fn stop_replay(&mut self) {
+        if self.active_implementation() == ImplementationKind::Replay {
+            self.set_implementation(HardwareMonitor::create_os_implementation());
+        }
+    }
+
+    /// Plugs in a custom backend, e.g. for exotic hardware or a remote rig.
+    /// Readings of the previous backend are dropped so maxima don't leak across backends.
+    pub fn set_implementation(&mut self, implementation: Box<dyn HardwareMonitorImpl>) {
+        implementation.set_failure_policy(self.failure_policy);
+        implementation.set_nvidia_smi_fallback(self.nvidia_smi_fallback);
+        self.current_implementation = Arc::from(implementation);
+        self.cpu = None;
+        self.gpu = vec![];
+        self.motherboard = vec![];
+        self.disks = vec![];
+    }
+
+    pub fn active_implementation(&self) -> ImplementationKind {
+        self.implementation().get_implementation_kind()
+    }
+
+    fn implementation(&self) -> &dyn HardwareMonitorImpl {
+        self.current_implementation.as_ref()
+    }
+
+    pub fn gpu_backend_status(&self) -> GpuBackendStatus {
+        self.implementation().gpu_backend_status()
+    }
+
+    pub fn effective_config(&self) -> HardwareMonitorConfig {
+        HardwareMonitorConfig {
+            implementation: self.active_implementation(),
+            include_integrated_gpus: self.include_integrated_gpus,
+            min_usage_filter: self.min_usage_filter,
+            status_delta_epsilon: self.status_delta_epsilon,
+            temperature_unit: self.temperature_unit,
+            plausibility_thresholds: self.plausibility_thresholds,
+            gpu_read_concurrency: self.implementation().gpu_read_concurrency(),
+            failure_policy: self.failure_policy,
+            history_memory_budget: self.history_memory_budget,
+            nvidia_smi_fallback: self.nvidia_smi_fallback,
+            temperature_average_window: self.temperature_average_window,
+            min_refresh_interval_ms: self.min_refresh_interval.as_millis(),
+            device_priorities_ms: self
+                .device_priorities
+                .iter()
+                .map(|(uuid, interval)| (uuid.clone(), interval.as_millis()))
+                .collect(),
+        }
+    }
+
+    fn initialize_nvml() -> Result<Arc<Nvml>, NvmlError> {
+        let nvml = Nvml::init();
+        match nvml {
+            Ok(nvml) => {
+                debug!(target: LOG_TARGET, "NVML initialized");
+                Ok(Arc::new(nvml))
+            }
+            Err(e) => {
+                warn!(target: LOG_TARGET, "Failed to initialize NVML: {}", e);
+                Err(e)
+            }
+        }
+    }
+
+    fn detect_current_os() -> CurrentOperatingSystem {
+        if cfg!(target_os = "windows") {
+            CurrentOperatingSystem::Windows
+        } else if cfg!(target_os = "linux") {
+            CurrentOperatingSystem::Linux
+        } else if cfg!(target_os = "macos") {
+            CurrentOperatingSystem::MacOS
+        } else {
+            warn!(target: LOG_TARGET, "Unsupported OS, reading hardware through sysinfo only");
+            CurrentOperatingSystem::Other
+        }
+    }
+
+    /// Reads every sensor of the global monitor, see `read_hardware_parameters_from`
+    pub async fn read_hardware_parameters() -> HardwareStatus {
+        HardwareMonitor::read_hardware_parameters_from(HardwareMonitor::current()).await
+    }
+
+    /// Reads every sensor on a blocking thread. The monitor lock is only taken to snapshot
+    /// the previous readings and to store the new ones, not while the CPU usage is sampled.
+    /// Taking the monitor lets tests drive their own instance instead of the global one.
+    pub async fn read_hardware_parameters_from(
+        hardware_monitor: &RwLock<HardwareMonitor>,
+    ) -> HardwareStatus {
+        loop {
+            let sample_time = SystemTime::now();
+            let (implementation, cpu, gpu, motherboard, disks) = {
+                let monitor = hardware_monitor.read().await;
+                if let Some(status) = monitor.recent_status(Instant::now()) {
+                    return status;
+                }
+                (
+                    Arc::clone(&monitor.current_implementation),
+                    monitor.cpu.clone(),
+                    monitor.gpu.clone(),
+                    monitor.motherboard.clone(),
+                    monitor.disks.clone(),
+                )
+            };
+            let read_implementation = Arc::clone(&implementation);
+            let readings = tokio::task::spawn_blocking(move || {
+                // USED FOR DEBUGGING
+                // debug!(target: LOG_TARGET, "Reading hardware parameters for {:?}", read_implementation.get_implementation_kind());
+                // read_implementation._log_all_components();
+                SensorReadings::read(read_implementation.as_ref(), cpu, gpu, motherboard, disks)
+            })
+            .await;
+
+            let mut monitor = hardware_monitor.write().await;
+            if !Arc::ptr_eq(&implementation, &monitor.current_implementation) {
+                // The readings would leak maxima of the previous backend into the new one
+                debug!(target: LOG_TARGET, "Backend switched during the read, reading again");
+                continue;
+            }
+            let readings = readings.unwrap_or_else(|e| {
+                error!(target: LOG_TARGET, "Hardware read task failed: {}", e);
+                SensorReadings {
+                    cpu: Err(HardwareMonitorError::ReadTask(e.to_string())),
+                    gpu: Err(HardwareMonitorError::ReadTask(e.to_string())),
+                    motherboard: monitor.motherboard.clone(),
+                    disks: monitor.disks.clone(),
+                    memory: None,
+                    power: None,
+                    has_gpu_sensors: monitor.implementation().has_gpu_sensors(),
+                }
+            });
+            let status = monitor.apply_readings(sample_time, readings);
+            monitor.last_read = Some((Instant::now(), status.clone()));
+            return status;
+        }
+    }
+
+    /// The status of the previous read while it is younger than the minimum refresh interval
+    pub(crate) fn recent_status(&self, now: Instant) -> Option<HardwareStatus> {
+        let (read_at, _) = self.last_read.as_ref()?;
+        if now.saturating_duration_since(*read_at) >= self.min_refresh_interval {
+            return None;
+        }
+        self.last_status()
+    }
+
+    /// The status of the most recent read without touching the sensors, `None` before the
+    /// first read. It is marked cached, or stale once it is too old.
+    pub fn last_status(&self) -> Option<HardwareStatus> {
+        let (_, status) = self.last_read.as_ref()?;
+        let source = match status.source {
+            ReadingSource::Fresh => ReadingSource::of_cached_sample(status.sample_time),
+            source => source,
+        };
+        Some(HardwareStatus {
+            source,
+            ..status.clone()
+        })
+    }
+
+    /// Reads requested within `interval` of the previous one return its status without
+    /// touching the sensors, zero reads on every call
+    pub fn set_min_refresh_interval(&mut self, interval: Duration) {
+        self.min_refresh_interval = interval;
+    }
+
+    /// Polls on the calling thread, for tests driving a monitor that is not the global instance
+    #[cfg(test)]
+    pub(crate) fn read_hardware_parameters_blocking(&mut self) -> HardwareStatus {
+        let sample_time = SystemTime::now();
+        let readings = SensorReadings::read(
+            self.implementation(),
+            self.cpu.clone(),
+            self.gpu.clone(),
+            self.motherboard.clone(),
+            self.disks.clone(),
+        );
+        let status = self.apply_readings(sample_time, readings);
+        self.last_read = Some((Instant::now(), status.clone()));
+        status
+    }
+
+    fn apply_readings(
+        &mut self,
+        sample_time: SystemTime,
+        readings: SensorReadings,
+    ) -> HardwareStatus {
+        let mut cpu = match readings.cpu {
+            Ok(cpu) => Some(cpu),
+            Err(e) => {
+                warn!(target: LOG_TARGET, "Failed to read CPU parameters: {}", e);
+                None
+            }
+        };
+        let mut gpu = match readings.gpu {
+            Ok(gpu) => gpu,
+            Err(e) => {
+                warn!(target: LOG_TARGET, "Failed to read GPU parameters, applying {:?}: {}", self.failure_policy, e);
+                self.gpu
+                    .iter()
+                    .filter_map(|gpu| self.failure_policy.failed_device(Some(gpu)))
+                    .collect()
+            }
+        };
+        let mut motherboard = readings.motherboard;
+        let mut disks = readings.disks;
+        self.average_temperatures(
+            cpu.iter_mut()
+                .chain(&mut gpu)
+                .chain(&mut motherboard)
+                .chain(&mut disks),
+        );
+        self.detect_new_ecc_errors(&gpu);
+        self.detect_new_peaks(cpu.as_ref(), &gpu);
+        self.detect_temperature_alerts(&gpu);
+        let alerts = self.detect_thermal_alerts(cpu.as_ref(), &gpu, &motherboard);
+        self.track_gpu_uptime(&gpu);
+        self.track_throttling(sample_time, &mut gpu);
+        self.record_history(sample_time, cpu.iter().chain(gpu.iter()));
+        self.predict_thermal_limit(&mut gpu);
+        if let Some(logger) = &self.csv_logger {
+            logger.log(sample_time, cpu.as_ref(), &gpu);
+        }
+        let implausible_readings = self.detect_implausible_readings(cpu.as_ref(), &gpu);
+
+        // The cache keeps every device so the positional max temperature tracking stays aligned
+        self.cpu = cpu.clone();
+        self.gpu = gpu.clone();
+        self.motherboard = motherboard.clone();
+        self.disks = disks.clone();
+
+        let gpu: Vec<HardwareParameters> = gpu
+            .into_iter()
+            .filter(|gpu| self.is_shown_gpu(gpu))
+            .collect();
+
+        let cpu_availability = match &cpu {
+            Some(cpu) if cpu.current_temperature.is_finite() => Availability::Available,
+            _ => Availability::Unavailable,
+        };
+        let gpu_availability = if readings.has_gpu_sensors {
+            Availability::Available
+        } else {
+            Availability::Unavailable
+        };
+
+        let unit = self.temperature_unit;
+        let names = &self.sensor_display_names;
+        HardwareStatus {
+            cpu: cpu.map(|cpu| cpu.with_temperature_unit(unit).with_display_name(names)),
+            gpu: gpu
+                .into_iter()
+                .map(|gpu| gpu.with_temperature_unit(unit).with_display_name(names))
+                .collect(),
+            motherboard: motherboard
+                .into_iter()
+                .map(|motherboard| {
+                    motherboard
+                        .with_temperature_unit(unit)
+                        .with_display_name(names)
+                })
+                .collect(),
+            disks: disks
+                .into_iter()
+                .map(|disk| disk.with_temperature_unit(unit).with_display_name(names))
+                .collect(),
+            cpu_availability,
+            gpu_availability,
+            temperature_unit: unit,
+            implausible_readings,
+            sample_time,
+            source: match self.active_implementation() {
+                ImplementationKind::Replay => ReadingSource::Simulated,
+                _ => ReadingSource::Fresh,
+            },
+            rig_id: self.rig_id.clone(),
+            alerts: alerts
+                .into_iter()
+                .map(|alert| ThermalAlert {
+                    temperature: unit.from_celsius(alert.temperature),
+                    ..alert
+                })
+                .collect(),
+            memory: readings.memory,
+            power_source: readings.power.map(|power| power.power_source),
+            battery_percentage: readings.power.and_then(|power| power.battery_percentage),
+        }
+    }
+
+    /// Flags readings that cannot be physically right, e.g. a loaded CPU at 0°C,
+    /// which usually means the wrong sensor was picked
+    fn detect_implausible_readings(
+        &self,
+        cpu: Option<&HardwareParameters>,
+        gpu: &[HardwareParameters],
+    ) -> bool {
+        let (Some(thresholds), Some(cpu)) = (&self.plausibility_thresholds, cpu) else {
+            return false;
+        };
+        if !cpu.current_temperature.is_finite() {
+            return false;
+        }
+
+        let mut implausible = false;
+        if cpu.usage_percentage >= thresholds.cpu_load_percentage
+            && cpu.current_temperature <= thresholds.min_loaded_cpu_temperature
+        {
+            warn!(target: LOG_TARGET, "Implausible CPU reading: {}°C at {}% usage", cpu.current_temperature, cpu.usage_percentage);
+            implausible = true;
+        }
+        for gpu in gpu.iter().filter(|gpu| gpu.current_temperature > 0.0) {
+            let divergence = (gpu.current_temperature - cpu.current_temperature).abs();
+            if divergence > thresholds.max_cpu_gpu_divergence {
+                warn!(target: LOG_TARGET, "Implausible temperature divergence: CPU at {}°C, {} at {}°C", cpu.current_temperature, gpu.label, gpu.current_temperature);
+                implausible = true;
+            }
+        }
+        implausible
+    }
+
+    /// `None` disables the sanity check of the readings
+    pub fn set_plausibility_thresholds(&mut self, thresholds: Option<PlausibilityThresholds>) {
+        self.plausibility_thresholds = thresholds;
+    }
+
+    pub fn set_temperature_unit(&mut self, temperature_unit: TemperatureUnit) {
+        self.temperature_unit = temperature_unit;
+    }
+
+    /// Bounds how many GPUs are queried at the same time on large rigs
+    pub fn set_gpu_read_concurrency(&mut self, concurrency: usize) {
+        self.implementation().set_gpu_read_concurrency(concurrency);
+    }
+
+    /// Shows the given raw sensor labels under friendly names, on top of the built-in defaults.
+    /// Only the output is renamed, readings are still matched by their raw label.
+    pub fn set_sensor_display_names(&mut self, display_names: HashMap<String, String>) {
+        let mut sensor_display_names = default_sensor_display_names();
+        sensor_display_names.extend(display_names);
+        self.sensor_display_names = sensor_display_names;
+    }
+
+    /// Tags the statuses read on this machine so they can be told apart in a fleet view
+    pub fn set_rig_id(&mut self, rig_id: Option<String>) {
+        self.rig_id = rig_id;
+    }
+
+    /// Lets Linux read NVIDIA GPUs through the slower nvidia-smi CLI when NVML fails to load
+    pub fn set_nvidia_smi_fallback(&mut self, enabled: bool) {
+        self.nvidia_smi_fallback = enabled;
+        self.implementation().set_nvidia_smi_fallback(enabled);
+    }
+
+    /// Decides whether failed GPU reads hold their last value or report defaults
+    pub fn set_failure_policy(&mut self, policy: FailurePolicy) {
+        self.failure_policy = policy;
+        self.implementation().set_failure_policy(policy);
+    }
+
+    pub fn set_include_integrated_gpus(&mut self, include_integrated_gpus: bool) {
+        self.include_integrated_gpus = include_integrated_gpus;
+    }
+
+    /// Leaves GPUs below the given usage out of the returned status, they are still tracked
+    pub fn set_min_usage_filter(&mut self, min_usage_filter: Option<f32>) {
+        self.min_usage_filter = min_usage_filter;
+    }
+
+    /// Describes only what changed beyond the epsilon since the last emitted status,
+    /// the first call after `reset_status_delta` contains every field
+    pub fn status_delta(&mut self) -> HardwareStatusDelta {
+        let previous = self.last_emitted_status.take();
+        let epsilon = self.status_delta_epsilon;
+        // The same filtered and relabelled devices as the full status
+        let names = &self.sensor_display_names;
+        let current_cpu = self.cpu.clone().map(|cpu| cpu.with_display_name(names));
+        let current_gpus: Vec<HardwareParameters> = self
+            .gpu
+            .iter()
+            .filter(|gpu| self.is_shown_gpu(gpu))
+            .map(|gpu| gpu.clone().with_display_name(names))
+            .collect();
+
+        let mut emitted_cpu = None;
+        let cpu = current_cpu.as_ref().and_then(|cpu| {
+            let previous_cpu = previous.as_ref().and_then(|p| p.cpu.as_ref());
+            let delta = parameters_delta(previous_cpu, cpu, epsilon);
+            emitted_cpu = Some(apply_parameters_delta(previous_cpu, cpu, delta.as_ref()));
+            delta
+        });
+
+        let mut emitted_gpu = vec![];
+        let mut gpu = vec![];
+        for (i, current_gpu) in current_gpus.iter().enumerate() {
+            let previous_gpu = previous
+                .as_ref()
+                .and_then(|p| find_previous_gpu_parameters(&p.gpu, current_gpu.uuid.as_deref(), i));
+            let delta = parameters_delta(previous_gpu, current_gpu, epsilon);
+            emitted_gpu.push(apply_parameters_delta(
+                previous_gpu,
+                current_gpu,
+                delta.as_ref(),
+            ));
+            gpu.extend(delta);
+        }
+
+        self.last_emitted_status = Some(HardwareStatus {
+            cpu: emitted_cpu,
+            gpu: emitted_gpu,
+            motherboard: vec![],
+            disks: vec![],
+            cpu_availability: Availability::Available,
+            gpu_availability: Availability::Available,
+            temperature_unit: TemperatureUnit::Celsius,
+            implausible_readings: false,
+            sample_time: SystemTime::now(),
+            source: ReadingSource::Fresh,
+            rig_id: None,
+            alerts: vec![],
+            memory: None,
+            power_source: None,
+            battery_percentage: None,
+        });
+
+        let unit = self.temperature_unit;
+        HardwareStatusDelta {
+            cpu: cpu.map(|cpu| cpu.with_temperature_unit(unit)),
+            gpu: gpu
+                .into_iter()
+                .map(|gpu| gpu.with_temperature_unit(unit))
+                .collect(),
+            gpu_count: current_gpus.len(),
+        }
+    }
+
+    /// Restarts the session maxima and minima of every device, e.g. after a thermal spike
+    pub fn reset_peak_temperatures(&mut self) {
+        for device in self
+            .cpu
+            .iter_mut()
+            .chain(self.gpu.iter_mut())
+            .chain(self.motherboard.iter_mut())
+            .chain(self.disks.iter_mut())
+        {
+            device.reset_peak_temperatures();
+        }
+    }
+
+    /// Forgets the last emitted status so the next delta is a full snapshot
+    pub fn reset_status_delta(&mut self) {
+        self.last_emitted_status = None;
+    }
+
+    pub fn set_status_delta_epsilon(&mut self, epsilon: f32) {
+        self.status_delta_epsilon = epsilon;
+    }
+
+    /// Registers a callback fired with the number of uncorrectable ECC errors
+    /// that occurred on a GPU since the previous poll
+    pub fn on_ecc_error_detected(&mut self, callback: EccErrorCallback) {
+        self.on_ecc_error_detected = Some(callback);
+    }
+
+    fn detect_new_ecc_errors(&self, gpu: &[HardwareParameters]) {
+        let callback = match &self.on_ecc_error_detected {
+            Some(callback) => callback,
+            None => return,
+        };
+        for current in gpu {
+            let (Some(uuid), Some(errors)) = (&current.uuid, current.uncorrected_ecc_errors) else {
+                continue;
+            };
+            let previous_errors = self
+                .gpu
+                .iter()
+                .find(|previous| previous.uuid.as_ref() == Some(uuid))
+                .and_then(|previous| previous.uncorrected_ecc_errors);
+            if let Some(previous_errors) = previous_errors {
+                if errors > previous_errors {
+                    warn!(target: LOG_TARGET, "GPU {} reported {} new uncorrectable ECC errors", uuid, errors - previous_errors);
+                    callback(uuid, errors - previous_errors);
+                }
+            }
+        }
+    }
+
+    /// Registers a callback fired when a GPU rises above its alert threshold
+    pub fn on_temperature_alert(&mut self, callback: TemperatureAlertCallback) {
+        self.on_temperature_alert = Some(callback);
+    }
+
+    /// Fires once per crossing, a device has to cool below its threshold to alert again
+    fn detect_temperature_alerts(&mut self, gpu: &[HardwareParameters]) {
+        for current in gpu {
+            let Some(uuid) = &current.uuid else {
+                continue;
+            };
+            let Some(threshold) = self.temperature_alerts.get(uuid).copied() else {
+                self.alerting_devices.remove(uuid);
+                continue;
+            };
+            if current.current_temperature < threshold {
+                self.alerting_devices.remove(uuid);
+            } else if self.alerting_devices.insert(uuid.clone()) {
+                warn!(target: LOG_TARGET, "GPU {} reached {}°C, above its alert at {}°C", uuid, current.current_temperature, threshold);
+                if let Some(callback) = &self.on_temperature_alert {
+                    let unit = self.temperature_unit;
+                    callback(
+                        uuid,
+                        unit.from_celsius(current.current_temperature),
+                        unit.from_celsius(threshold),
+                    );
+                }
+            } else {
+                // Still above the threshold, already alerted
+            }
+        }
+    }
+
+    /// Records every device that rose to a higher level than on the previous poll
+    fn detect_thermal_alerts(
+        &mut self,
+        cpu: Option<&HardwareParameters>,
+        gpu: &[HardwareParameters],
+        motherboard: &[HardwareParameters],
+    ) -> Vec<ThermalAlert> {
+        let devices = cpu
+            .into_iter()
+            .map(|cpu| (ComponentKind::Cpu, cpu))
+            .chain(gpu.iter().map(|gpu| (ComponentKind::Gpu, gpu)))
+            .chain(
+                motherboard
+                    .iter()
+                    .map(|board| (ComponentKind::Motherboard, board)),
+            );
+
+        let mut alerts = vec![];
+        for (component, device) in devices {
+            let key = device.uuid.clone().unwrap_or_else(|| device.label.clone());
+            let Some(thresholds) = self.thermal_thresholds.get(&component).copied() else {
+                self.thermal_levels.remove(&key);
+                continue;
+            };
+            let previous = self.thermal_levels.get(&key).copied().unwrap_or_default();
+            let level = thresholds.level(previous, device.current_temperature);
+            if level > previous {
+                warn!(target: LOG_TARGET, "{} reached {:?} at {}°C", device.label, level, device.current_temperature);
+                alerts.push(ThermalAlert {
+                    label: device.label.clone(),
+                    component,
+                    level,
+                    temperature: device.current_temperature,
+                });
+            }
+            self.thermal_levels.insert(key, level);
+        }
+        alerts
+    }
+
+    /// Sets the warning and critical temperatures of a kind of component, given in the
+    /// configured unit, `None` disables its alerts
+    pub fn set_thermal_thresholds(
+        &mut self,
+        component: ComponentKind,
+        thresholds: Option<ThermalThresholds>,
+    ) {
+        match thresholds {
+            Some(thresholds) => {
+                let unit = self.temperature_unit;
+                self.thermal_thresholds.insert(
+                    component,
+                    thresholds.map_temperatures(|t| unit.to_celsius(t)),
+                );
+            }
+            None => {
+                self.thermal_thresholds.remove(&component);
+            }
+        }
+    }
+
+    /// Alerts when the GPU reaches the threshold, given in the configured unit, `None` clears it
+    pub fn set_temperature_alert(&mut self, uuid: String, threshold: Option<f32>) {
+        match threshold {
+            Some(threshold) => {
+                let threshold = self.temperature_unit.to_celsius(threshold);
+                self.temperature_alerts.insert(uuid, threshold);
+            }
+            None => {
+                self.temperature_alerts.remove(&uuid);
+            }
+        }
+    }
+
+    /// Loads the alerts saved in `hardware_alerts.json`, no file means no alerts
+    pub fn load_alerts(&mut self, config_path: PathBuf) -> Result<(), anyhow::Error> {
+        let file = config_path.join("hardware_alerts.json");
+        self.alerts_file = Some(file.clone());
+        if !file.exists() {
+            debug!(target: LOG_TARGET, "No hardware alerts saved at {:?}", file);
+            return Ok(());
+        }
+        let content = fs::read_to_string(&file)
+            .map_err(|e| anyhow!("Failed to read hardware alerts {:?}: {}", file, e))?;
+        self.temperature_alerts = serde_json::from_str(&content)
+            .map_err(|e| anyhow!("Failed to parse hardware alerts {:?}: {}", file, e))?;
+        info!(target: LOG_TARGET, "Loaded {} hardware alerts", self.temperature_alerts.len());
+        Ok(())
+    }
+
+    /// Saves the alert thresholds, in Celsius, next to the app config
+    pub fn save_alerts(&self) -> Result<(), anyhow::Error> {
+        let file = self
+            .alerts_file
+            .as_ref()
+            .ok_or_else(|| anyhow!("Hardware alerts file not set"))?;
+        fs::write(file, serde_json::to_string(&self.temperature_alerts)?)
+            .map_err(|e| anyhow!("Failed to save hardware alerts {:?}: {}", file, e))
+    }
+
+    /// Registers a callback fired with the device label and its new maximum temperature
+    /// whenever a device sets a new high during the session
+    pub fn on_new_peak(&mut self, callback: NewPeakCallback) {
+        self.on_new_peak = Some(callback);
+    }
+
+    fn detect_new_peaks(&self, cpu: Option<&HardwareParameters>, gpu: &[HardwareParameters]) {
+        let callback = match &self.on_new_peak {
+            Some(callback) => callback,
+            None => return,
+        };
+        let previous_cpu = self.cpu.as_ref();
+        let devices =
+            cpu.map(|cpu| (cpu, previous_cpu))
+                .into_iter()
+                .chain(gpu.iter().enumerate().map(|(i, current)| {
+                    (
+                        current,
+                        find_previous_gpu_parameters(&self.gpu, current.uuid.as_deref(), i),
+                    )
+                }));
+        for (current, previous) in devices {
+            // The first reading only sets the baseline
+            if let Some(previous) = previous {
+                if current.max_temperature > previous.max_temperature {
+                    callback(
+                        &current.label,
+                        self.temperature_unit.from_celsius(current.max_temperature),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Fills in `average_temperature` from each device's window, keyed by uuid or label
+    fn average_temperatures<'a>(
+        &mut self,
+        devices: impl Iterator<Item = &'a mut HardwareParameters>,
+    ) {
+        for device in devices {
+            let key = device.uuid.clone().unwrap_or_else(|| device.label.clone());
+            let window = self.temperature_windows.entry(key).or_default();
+            device.average_temperature = rolling_average(
+                window,
+                self.temperature_average_window,
+                device.current_temperature,
+            );
+        }
+    }
+
+    /// Sets how many of the last readings are averaged into `average_temperature`
+    pub fn set_temperature_average_window(&mut self, length: usize) {
+        self.temperature_average_window = length.max(1);
+    }
+
+    /// Starts appending every read to a CSV file at `path`, rotated once it exceeds `max_size_bytes`
+    pub fn enable_csv_logging(&mut self, path: PathBuf, max_size_bytes: Option<u64>) {
+        info!(target: LOG_TARGET, "Logging hardware readings to {:?}", path);
+        let mut logger = HardwareCsvLogger::new(path);
+        if let Some(bytes) = max_size_bytes {
+            logger.set_max_size(bytes);
+        }
+        self.csv_logger = Some(CsvLogWriter::spawn(logger));
+    }
+
+    pub fn disable_csv_logging(&mut self) {
+        self.csv_logger = None;
+    }
+
+    /// Warns about GPUs whose temperature trend crosses `limit` (in the configured unit)
+    /// within 30 seconds, `None` turns the prediction off
+    pub fn set_thermal_limit(&mut self, limit: Option<f32>) {
+        self.thermal_limit = limit.map(|limit| self.temperature_unit.to_celsius(limit));
+        self.approaching_limit_devices.clear();
+    }
+
+    /// Flags the GPUs projected to reach the thermal limit from their recent history.
+    /// A flagged GPU stays flagged until its projection drops below the limit by the hysteresis.
+    fn predict_thermal_limit(&mut self, gpu: &mut [HardwareParameters]) {
+        let Some(limit) = self.thermal_limit else {
+            return;
+        };
+        for gpu in gpu.iter_mut() {
+            let key = gpu.uuid.clone().unwrap_or_else(|| gpu.label.clone());
+            let samples: Vec<HistorySample> = self
+                .history
+                .get(&key)
+                .map(|history| {
+                    let skipped = history
+                        .len()
+                        .saturating_sub(THERMAL_LIMIT_PREDICTION_SAMPLES);
+                    history.iter().skip(skipped).cloned().collect()
+                })
+                .unwrap_or_default();
+            let threshold = if self.approaching_limit_devices.contains(&key) {
+                limit - THERMAL_ALERT_HYSTERESIS
+            } else {
+                limit
+            };
+            // Past the limit the card is throttling already rather than approaching it
+            gpu.approaching_limit = gpu.current_temperature < limit
+                && projected_temperature(&samples, THERMAL_LIMIT_PREDICTION_HORIZON)
+                    .is_some_and(|projected| projected >= threshold);
+            if gpu.approaching_limit {
+                if self.approaching_limit_devices.insert(key) {
+                    warn!(target: LOG_TARGET, "{} is approaching its thermal limit of {}°C", gpu.label, limit);
+                }
+            } else {
+                self.approaching_limit_devices.remove(&key);
+            }
+        }
+    }
+
+    /// Appends the readings to the per-device history, keyed by uuid or label
+    fn record_history<'a>(
+        &mut self,
+        sample_time: SystemTime,
+        devices: impl Iterator<Item = &'a HardwareParameters>,
+    ) {
+        for device in devices {
+            let key = device.uuid.clone().unwrap_or_else(|| device.label.clone());
+            self.history
+                .entry(key)
+                .or_default()
+                .push_back(HistorySample {
+                    sample_time,
+                    usage_percentage: device.usage_percentage,
+                    temperature: device.current_temperature,
+                });
+        }
+        self.enforce_history_memory_budget();
+    }
+
+    /// Evicts the oldest samples across all devices until the histories fit the budget
+    fn enforce_history_memory_budget(&mut self) {
+        let max_samples = self.history_memory_budget / std::mem::size_of::<HistorySample>();
+        let mut retained: usize = self.history.values().map(VecDeque::len).sum();
+        while retained > max_samples {
+            let oldest = self
+                .history
+                .values_mut()
+                .filter(|samples| !samples.is_empty())
+                .min_by_key(|samples| samples.front().map(|sample| sample.sample_time));
+            match oldest {
+                Some(samples) => {
+                    samples.pop_front();
+                    retained -= 1;
+                }
+                None => break,
+            }
+        }
+        self.history.retain(|_, samples| !samples.is_empty());
+    }
+
+    /// Bounds the memory taken by all device histories together, oldest samples go first
+    pub fn set_history_memory_budget(&mut self, bytes: usize) {
+        self.history_memory_budget = bytes;
+        self.enforce_history_memory_budget();
+    }
+
+    /// How far back the histories reach within the memory budget
+    pub fn history_retained_duration(&self) -> Duration {
+        self.history
+            .values()
+            .filter_map(|samples| samples.front())
+            .map(|sample| sample.sample_time)
+            .min()
+            .and_then(|oldest| oldest.elapsed().ok())
+            .unwrap_or_default()
+    }
+
+    /// Samples of a device by uuid, or by label for devices without one
+    pub fn history(&self, device: &str) -> Vec<HistorySample> {
+        self.history
+            .get(device)
+            .map(|samples| samples.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// A GPU that drops off the bus and comes back has been reset by the driver,
+    /// so its uptime starts over and the reset is counted
+    fn track_gpu_uptime(&mut self, gpu: &[HardwareParameters]) {
+        let current_uuids: HashSet<&str> = gpu.iter().filter_map(|g| g.uuid.as_deref()).collect();
+
+        let vanished: Vec<String> = self
+            .gpu_first_seen
+            .keys()
+            .filter(|uuid| !current_uuids.contains(uuid.as_str()))
+            .cloned()
+            .collect();
+        for uuid in vanished {
+            warn!(target: LOG_TARGET, "GPU {} is no longer visible", uuid);
+            self.gpu_first_seen.remove(&uuid);
+            self.vanished_gpus.insert(uuid);
+        }
+
+        for uuid in current_uuids {
+            if self.gpu_first_seen.contains_key(uuid) {
+                continue;
+            }
+            if self.vanished_gpus.remove(uuid) {
+                let resets = self.gpu_resets.entry(uuid.to_string()).or_default();
+                *resets += 1;
+                warn!(target: LOG_TARGET, "GPU {} reappeared after a reset ({} resets this session)", uuid, resets);
+            }
+            self.gpu_first_seen.insert(uuid.to_string(), Instant::now());
+        }
+    }
+
+    /// Accumulates how long each GPU, keyed by uuid or label, has been throttling.
+    /// The idle reason is not counted, an idle card is not held back.
+    pub(crate) fn track_throttling(
+        &mut self,
+        sample_time: SystemTime,
+        gpu: &mut [HardwareParameters],
+    ) {
+        let mut throttling_gpus = HashMap::new();
+        for gpu in gpu.iter_mut() {
+            let is_throttling = gpu
+                .throttle_reasons
+                .iter()
+                .any(|reason| reason != NVML_IDLE_THROTTLE_REASON);
+            if !is_throttling {
+                gpu.throttle_duration_ms = 0;
+                continue;
+            }
+            let key = gpu.uuid.clone().unwrap_or_else(|| gpu.label.clone());
+            let duration_ms =
+                self.throttling_gpus
+                    .get(&key)
+                    .map_or(0, |(last_sample_time, duration_ms)| {
+                        let elapsed = sample_time
+                            .duration_since(*last_sample_time)
+                            .unwrap_or_default();
+                        duration_ms
+                            .saturating_add(u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX))
+                    });
+            gpu.throttle_duration_ms = duration_ms;
+            throttling_gpus.insert(key, (sample_time, duration_ms));
+        }
+        self.throttling_gpus = throttling_gpus;
+    }
+
+    /// Time since the monitor first saw the GPU, or since its last reset
+    pub fn gpu_uptime(&self, uuid: &str) -> Option<Duration> {
+        self.gpu_first_seen
+            .get(uuid)
+            .map(|first_seen| first_seen.elapsed())
+    }
+
+    pub fn gpu_uptimes(&self) -> Vec<GpuUptime> {
+        let xid_errors = self.implementation().gpu_xid_errors();
+        self.gpu
+            .iter()
+            .filter_map(|gpu| {
+                let uuid = gpu.uuid.as_ref()?;
+                let xid = xid_errors.get(uuid).cloned().unwrap_or_default();
+                Some(GpuUptime {
+                    uuid: uuid.clone(),
+                    label: gpu.label.clone(),
+                    uptime_secs: self.gpu_uptime(uuid)?.as_secs(),
+                    resets: self.gpu_resets.get(uuid).copied().unwrap_or_default(),
+                    xid_errors: xid.count,
+                    last_xid: xid.last_xid,
+                })
+            })
+            .collect()
+    }
+
+    /// Overrides the poll interval of a single GPU, e.g. while its detail view is open
+    pub fn set_device_priority(&mut self, uuid: String, interval: Duration) {
+        self.device_priorities.insert(uuid, interval);
+    }
+
+    pub fn clear_device_priority(&mut self, uuid: &str) {
+        self.device_priorities.remove(uuid);
+    }
+
+    pub fn has_device_priority(&self, uuid: &str) -> bool {
+        self.device_priorities.contains_key(uuid)
+    }
+
+    /// Reads the hardware in a single background task and broadcasts every status, so any
+    /// number of subscribers share one refresh. The task stops once every receiver is dropped.
+    /// While it runs, further subscribers get its statuses at the interval it was started with.
+    pub fn spawn_polling(&mut self, interval: Duration) -> broadcast::Receiver<HardwareStatus> {
+        if let Some(sender) = &self.polling_sender {
+            if sender.receiver_count() > 0 {
+                return sender.subscribe();
+            }
+        }
+
+        let (sender, receiver) = broadcast::channel(POLLING_CHANNEL_CAPACITY);
+        self.polling_sender = Some(sender.clone());
+        tokio::spawn(async move {
+            info!(target: LOG_TARGET, "Started hardware polling every {:?}", interval);
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                let status = HardwareMonitor::read_hardware_parameters().await;
+                if sender.send(status).is_err() {
+                    break;
+                }
+            }
+            info!(target: LOG_TARGET, "Stopped hardware polling, no subscribers left");
+        });
+        receiver
+    }
+
+    /// Reloads the gpu devices whenever the GPU miner rewrites the status file and broadcasts
+    /// the new list. The watcher stops once every receiver is dropped.
+    pub fn watch_gpu_status_file(
+        &mut self,
+        config_path: PathBuf,
+    ) -> Result<broadcast::Receiver<Vec<GpuStatus>>, anyhow::Error> {
+        if let Some(sender) = &self.gpu_devices_sender {
+            if sender.receiver_count() > 0 {
+                return Ok(sender.subscribe());
+            }
+        }
+
+        let file = gpu_status_file_path(&config_path);
+        let dir = file
+            .parent()
+            .ok_or_else(|| anyhow!("Invalid gpu status file path {:?}", file))?;
+        fs::create_dir_all(dir)?;
+        let (event_sender, mut events) = tokio::sync::mpsc::unbounded_channel();
+        let mut watcher =
+            notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+                // Fails only once the watch task stopped and the watcher is being dropped
+                let _unused = event_sender.send(event);
+            })?;
+        // The directory is watched as the file may be replaced rather than written in place
+        watcher.watch(dir, RecursiveMode::NonRecursive)?;
+
+        let (sender, receiver) = broadcast::channel(GPU_DEVICES_CHANNEL_CAPACITY);
+        self.gpu_devices_sender = Some(sender.clone());
+        tokio::spawn(async move {
+            // Owned by the task, so the directory is watched for as long as it runs
+            let _watcher = watcher;
+            info!(target: LOG_TARGET, "Started watching gpu status file {:?}", file);
+            while let Some(event) = events.recv().await {
+                let event = match event {
+                    Ok(event) => event,
+                    Err(e) => {
+                        warn!(target: LOG_TARGET, "Failed to watch gpu status file: {}", e);
+                        continue;
+                    }
+                };
+                // Reading the file back must not trigger another reload
+                let is_status_file_change = !event.kind.is_access()
+                    && event
+                        .paths
+                        .iter()
+                        .any(|path| path.file_name() == file.file_name());
+                if !is_status_file_change {
+                    continue;
+                }
+
+                tokio::time::sleep(GPU_STATUS_WATCH_DEBOUNCE).await;
+                while events.try_recv().is_ok() {}
+                let mut monitor = HardwareMonitor::current().write().await;
+                // The backend only picks the file up once it exists
+                if let Err(e) = monitor.load_status_file(config_path.clone()) {
+                    warn!(target: LOG_TARGET, "{}", e);
+                }
+                let devices = monitor.read_gpu_devices();
+                drop(monitor);
+                if sender.send(devices).is_err() {
+                    break;
+                }
+            }
+            info!(target: LOG_TARGET, "Stopped watching gpu status file, no subscribers left");
+        });
+        Ok(receiver)
+    }
+
+    /// Polls the prioritized device at its own interval and broadcasts every reading. The task
+    /// stops once the priority is cleared or every receiver is dropped.
+    /// The device is read on a blocking thread without holding the monitor lock, a read that
+    /// hangs is given up after `GPU_READ_TIMEOUT` and the device is skipped until it returns.
+    pub fn poll_prioritized_device(
+        uuid: String,
+    ) -> broadcast::Receiver<GpuDeviceParametersEventPayload> {
+        let (sender, receiver) = broadcast::channel(PRIORITIZED_DEVICE_CHANNEL_CAPACITY);
+        tokio::spawn(async move {
+            info!(target: LOG_TARGET, "Started prioritized polling of GPU {}", uuid);
+            let mut hung_read: Option<tokio::task::JoinHandle<Option<HardwareParameters>>> = None;
+            loop {
+                let interval = match HardwareMonitor::current()
+                    .read()
+                    .await
+                    .device_priorities
+                    .get(&uuid)
+                {
+                    Some(interval) => *interval,
+                    None => break,
+                };
+                tokio::time::sleep(interval).await;
+
+                if hung_read.as_ref().is_some_and(|read| !read.is_finished()) {
+                    debug!(target: LOG_TARGET, "GPU {} is still being read, skipping", uuid);
+                    continue;
+                }
+                let (implementation, current_parameters) = {
+                    let monitor = HardwareMonitor::current().read().await;
+                    (
+                        Arc::clone(&monitor.current_implementation),
+                        monitor
+                            .gpu
+                            .iter()
+                            .find(|gpu| gpu.uuid.as_deref() == Some(uuid.as_str()))
+                            .cloned(),
+                    )
+                };
+                let read_implementation = Arc::clone(&implementation);
+                let read_uuid = uuid.clone();
+                let mut read = tokio::task::spawn_blocking(move || {
+                    read_implementation.read_gpu_device_parameters(&read_uuid, current_parameters)
+                });
+                hung_read = None;
+                let parameters = match tokio::time::timeout(GPU_READ_TIMEOUT, &mut read).await {
+                    Ok(Ok(parameters)) => parameters,
+                    Ok(Err(e)) => {
+                        error!(target: LOG_TARGET, "GPU {} read task failed: {}", uuid, e);
+                        None
+                    }
+                    Err(_) => {
+                        warn!(target: LOG_TARGET, "Reading GPU {} timed out", uuid);
+                        hung_read = Some(read);
+                        None
+                    }
+                };
+                let parameters = match parameters {
+                    Some(parameters) => HardwareMonitor::current()
+                        .write()
+                        .await
+                        .store_gpu_device_parameters(&implementation, &uuid, parameters),
+                    None => None,
+                };
+                if let Some(parameters) = parameters {
+                    let payload = GpuDeviceParametersEventPayload {
+                        uuid: uuid.clone(),
+                        parameters,
+                    };
+                    if sender.send(payload).is_err() {
+                        break;
+                    }
+                }
+            }
+            info!(target: LOG_TARGET, "Stopped prioritized polling of GPU {}", uuid);
+        });
+        receiver
+    }
+
+    /// Keeps a reading of a prioritized device, `None` if the backend was switched during the read
+    fn store_gpu_device_parameters(
+        &mut self,
+        implementation: &Arc<dyn HardwareMonitorImpl>,
+        uuid: &str,
+        parameters: HardwareParameters,
+    ) -> Option<HardwareParameters> {
+        if !Arc::ptr_eq(implementation, &self.current_implementation) {
+            return None;
+        }
+        if let Some(gpu) = self
+            .gpu
+            .iter_mut()
+            .find(|gpu| gpu.uuid.as_deref() == Some(uuid))
+        {
+            *gpu = parameters.clone();
+        }
+        Some(parameters.with_temperature_unit(self.temperature_unit))
+    }
+
+    pub fn read_gpu_devices(&mut self) -> Vec<GpuStatus> {
+        let gpu_dev = self.implementation().read_gpu_devices();
+        self.record_gpu_status_transitions(&gpu_dev);
+        self.gpu_devices = gpu_dev.clone();
+        gpu_dev
+    }
+
+    /// Whether a GPU passes the integrated GPU, minimum usage and exclusion filters
+    fn is_shown_gpu(&self, gpu: &HardwareParameters) -> bool {
+        (self.include_integrated_gpus || !gpu.is_integrated)
+            && self
+                .min_usage_filter
+                .map_or(true, |min_usage| gpu.usage_percentage >= min_usage)
+            && !self.is_excluded_gpu(gpu)
+    }
+
+    /// The status file only names devices, so every GPU sharing an excluded name is left out
+    fn is_excluded_gpu(&self, gpu: &HardwareParameters) -> bool {
+        self.gpu_devices
+            .iter()
+            .any(|device| device.is_excluded && device.device_name == gpu.label)
+    }
+
+    /// Saves the devices enabled or disabled by the user to the gpu status file
+    pub fn write_gpu_devices(
+        &mut self,
+        config_path: PathBuf,
+        devices: Vec<GpuStatus>,
+    ) -> Result<(), anyhow::Error> {
+        write_gpu_status_file(&gpu_status_file_path(&config_path), devices.clone())?;
+        // The backend only picks the file up once it exists
+        self.load_status_file(config_path)?;
+        self.record_gpu_status_transitions(&devices);
+        self.gpu_devices = devices;
+        Ok(())
+    }
+
+    /// Logs every device whose availability flipped since the previous read of the status file
+    fn record_gpu_status_transitions(&mut self, gpu_devices: &[GpuStatus]) {
+        let timestamp = SystemTime::now();
+        for device in gpu_devices {
+            let previous = self
+                .gpu_devices
+                .iter()
+                .find(|previous| previous.device_name == device.device_name);
+            if let Some(previous) = previous {
+                if previous.is_available != device.is_available {
+                    info!(target: LOG_TARGET, "GPU {} availability changed to {}", device.device_name, device.is_available);
+                    self.gpu_status_history.push(GpuStatusTransition {
+                        timestamp,
+                        device_name: device.device_name.clone(),
+                        was_available: previous.is_available,
+                        is_available: device.is_available,
+                    });
+                }
+            }
+        }
+        if self.gpu_status_history.len() > MAX_GPU_STATUS_HISTORY {
+            let excess = self.gpu_status_history.len() - MAX_GPU_STATUS_HISTORY;
+            self.gpu_status_history.drain(..excess);
+        }
+    }
+
+    /// The cached readings in the Prometheus text exposition format, without reading the sensors
+    pub fn prometheus_metrics(&self) -> String {
+        prometheus_metrics::render(self.cpu.as_ref(), &self.gpu)
+    }
+
+    /// Devices of the status file as of its last read, without touching the disk
+    pub fn gpu_devices(&self) -> &[GpuStatus] {
+        &self.gpu_devices
+    }
+
+    /// Availability changes of the GPUs in the status file, oldest first
+    pub fn gpu_status_history(&self) -> &[GpuStatusTransition] {
+        &self.gpu_status_history
+    }
+    pub fn load_status_file(&mut self, config_path: PathBuf) -> Result<(), anyhow::Error> {
+        match self.implementation().load_status_file(config_path) {
+            Ok(_) => {
+                trace!(target: LOG_TARGET, "Gpu status file loaded successfully");
+                Ok(())
+            }
+            Err(e) => Err(anyhow!("Fail to load gpu status file: {:?}", e)),
+        }
+    }
+}
+
+/// Name fragments of GPUs that share memory with the CPU
+const INTEGRATED_GPU_NAMES: [&str; 5] = [
+    "uhd graphics",
+    "hd graphics",
+    "iris",
+    "radeon graphics",
+    "apple m",
+];
+
+fn is_integrated_gpu(label: &str) -> bool {
+    let label = label.to_lowercase().replace("(tm)", "");
+    INTEGRATED_GPU_NAMES.iter().any(|name| label.contains(name))
+}
+
+fn nvml_power_draw_watts(device: &Device) -> Option<f32> {
+    // NVML reports power usage in milliwatts
+    device.power_usage().ok().map(|power| power as f32 / 1000.0)
+}
+
+/// The default power limit is the closest NVML gets to the card's TDP
+fn nvml_default_power_limit_watts(device: &Device) -> Option<f32> {
+    device
+        .power_management_limit_default()
+        .ok()
+        .map(|limit| limit as f32 / 1000.0)
+}
+
+fn parameters_delta(
+    previous: Option<&HardwareParameters>,
+    current: &HardwareParameters,
+    epsilon: f32,
+) -> Option<HardwareParametersDelta> {
+    let changed = |previous_value: Option<f32>, current_value: f32| match previous_value {
+        Some(previous_value) if (current_value - previous_value).abs() < epsilon => None,
+        _ => Some(current_value),
+    };
+
+    let delta = HardwareParametersDelta {
+        label: current.label.clone(),
+        uuid: current.uuid.clone(),
+        usage_percentage: changed(
+            previous.map(|p| p.usage_percentage),
+            current.usage_percentage,
+        ),
+        current_temperature: changed(
+            previous.map(|p| p.current_temperature),
+            current.current_temperature,
+        ),
+        max_temperature: changed(previous.map(|p| p.max_temperature), current.max_temperature),
+    };
+
+    if delta.usage_percentage.is_none()
+        && delta.current_temperature.is_none()
+        && delta.max_temperature.is_none()
+    {
+        None
+    } else {
+        Some(delta)
+    }
+}
+
+/// Builds the new baseline for a device, only moving the fields that were emitted
+/// so slow drifts still add up to a reported change
+fn apply_parameters_delta(
+    previous: Option<&HardwareParameters>,
+    current: &HardwareParameters,
+    delta: Option<&HardwareParametersDelta>,
+) -> HardwareParameters {
+    let mut emitted = previous.unwrap_or(current).clone();
+    if let Some(delta) = delta {
+        if let Some(usage_percentage) = delta.usage_percentage {
+            emitted.usage_percentage = usage_percentage;
+        }
+        if let Some(current_temperature) = delta.current_temperature {
+            emitted.current_temperature = current_temperature;
+        }
+        if let Some(max_temperature) = delta.max_temperature {
+            emitted.max_temperature = max_temperature;
+        }
+    }
+    emitted
+}
+
+/// Finds the previous reading of a GPU by its uuid, so when a device disappears
+/// the maxima of the remaining ones are not shifted onto the wrong device
+pub(crate) fn find_previous_gpu_parameters<'a>(
+    previous_parameters: &'a [HardwareParameters],
+    uuid: Option<&str>,
+    index: usize,
+) -> Option<&'a HardwareParameters> {
+    match uuid {
+        Some(uuid) => previous_parameters
+            .iter()
+            .find(|previous| previous.uuid.as_deref() == Some(uuid)),
+        None => previous_parameters
+            .get(index)
+            .filter(|previous| previous.uuid.is_none()),
+    }
+}
+
+/// One entry per fan, empty for passively cooled cards or drivers without fan control
+fn nvml_fan_speeds(
+    device: &Device,
+    previous: Option<&HardwareParameters>,
+    failure_policy: FailurePolicy,
+) -> Vec<u32> {
+    let num_fans = device.num_fans().unwrap_or_default();
+    (0..num_fans)
+        .map(|fan| match device.fan_speed(fan) {
+            Ok(speed) => speed,
+            Err(e) => {
+                debug!(target: LOG_TARGET, "Failed to read speed of fan {}: {}", fan, e);
+                failure_policy.fallback(
+                    previous.and_then(|p| p.fan_speeds_percentage.get(fan as usize).copied()),
+                )
+            }
+        })
+        .collect()
+}
+
+fn nvml_slowdown_temperature(device: &Device) -> Option<f32> {
+    device
+        .temperature_threshold(TemperatureThreshold::Slowdown)
+        .ok()
+        .map(|threshold| threshold as f32)
+}
+
+/// NVML only tells whether a display is attached, not how many, so this is 0 or 1
+fn nvml_connected_displays(device: &Device) -> Option<u32> {
+    device.is_display_connected().ok().map(u32::from)
+}
+
+/// Utilization per watt, a standalone proxy for hashrate per watt
+fn compute_efficiency(usage_percentage: f32, power_draw_watts: Option<f32>) -> Option<f32> {
+    power_draw_watts
+        .filter(|watts| *watts > 0.0)
+        .map(|watts| usage_percentage / watts)
+}
+
+/// Some enterprise drivers fail `name()` with `NotSupported` while the other sensors work,
+/// so fall back to the brand, the uuid or the enumeration index
+fn nvml_device_label(device: &Device, index: u32) -> String {
+    device.name().unwrap_or_else(|e| {
+        warn!(target: LOG_TARGET, "Failed to get name of GPU {}: {}", index, e);
+        device
+            .brand()
+            .map(|brand| format!("{:?} GPU {}", brand, index))
+            .or_else(|_| device.uuid())
+            .unwrap_or_else(|_| format!("GPU {}", index))
+    })
+}
+
+/// Longest wait for an NVML event, also how long the watcher outlives a dropped reader
+#[cfg(target_os = "linux")]
+const XID_EVENT_WAIT_MS: u32 = 1000;
+
+/// Counts the critical XID errors of every GPU on a background thread,
+/// which stops once the reader owning `xid_errors` is dropped
+#[cfg(target_os = "linux")]
+fn watch_nvml_xid_errors(nvml: Arc<Nvml>, xid_errors: Weak<Mutex<HashMap<String, XidErrors>>>) {
+    std::thread::spawn(move || {
+        let Some(events) = register_nvml_xid_events(&nvml) else {
+            return;
+        };
+        while let Some(xid_errors) = xid_errors.upgrade() {
+            match events.wait(XID_EVENT_WAIT_MS) {
+                Ok(event) => {
+                    let Ok(uuid) = event.device.uuid() else {
+                        continue;
+                    };
+                    warn!(target: LOG_TARGET, "GPU {} reported XID error {:?}", uuid, event.event_data);
+                    let mut xid_errors = xid_errors.lock().unwrap_or_else(PoisonError::into_inner);
+                    let errors = xid_errors.entry(uuid).or_default();
+                    errors.count += 1;
+                    errors.last_xid = event.event_data;
+                }
+                Err(NvmlError::Timeout) => {}
+                Err(e) => {
+                    warn!(target: LOG_TARGET, "Stopped watching GPU XID errors: {}", e);
+                    return;
+                }
+            }
+        }
+    });
+}
+
+/// NVML only delivers events on Linux, so on other platforms no XID error is ever counted
+#[cfg(not(target_os = "linux"))]
+fn watch_nvml_xid_errors(_nvml: Arc<Nvml>, _xid_errors: Weak<Mutex<HashMap<String, XidErrors>>>) {}
+
+/// An event set with every GPU that supports critical XID events registered
+#[cfg(target_os = "linux")]
+fn register_nvml_xid_events(nvml: &Nvml) -> Option<nvml_wrapper::EventSet<'_>> {
+    use nvml_wrapper::bitmasks::event::EventTypes;
+
+    let mut events = match nvml.create_event_set() {
+        Ok(events) => events,
+        Err(e) => {
+            debug!(target: LOG_TARGET, "Failed to create an NVML event set: {}", e);
+            return None;
+        }
+    };
+    for index in 0..nvml.device_count().ok()? {
+        let Ok(device) = nvml.device_by_index(index) else {
+            continue;
+        };
+        events = match device.register_events(EventTypes::CRITICAL_XID_ERROR, events) {
+            Ok(events) => events,
+            Err(e) => {
+                debug!(target: LOG_TARGET, "GPU {} does not report XID errors: {}", index, e.error);
+                // The set is handed back unless NVML failed to clean up after the error
+                e.source?
+            }
+        };
+    }
+    Some(events)
+}
+
+/// A device whose NVML queries take longer than this keeps its previous values for the poll
+const GPU_READ_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Outcome of the thread reading one NVML device, by enumeration index
+enum NvmlGpuRead {
+    Read(u32, HardwareParameters),
+    Failed(u32),
+}
+
+/// Devices whose read thread is still running, by uuid
+type NvmlReadsInFlight = Arc<Mutex<HashSet<String>>>;
+
+/// Clears the in-flight mark of a device once its read thread ends, even if the read panics
+struct InFlightRead {
+    in_flight: NvmlReadsInFlight,
+    key: String,
+}
+
+impl Drop for InFlightRead {
+    fn drop(&mut self) {
+        self.in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&self.key);
+    }
+}
+
+/// Reads every NVML device, querying at most `concurrency` devices at the same time.
+/// The driver serializes many NVML calls internally, so the real speedup may be lower.
+/// Each device is read on its own thread so a hanging driver call only stalls that device.
+/// A device whose thread from an earlier poll has not returned yet is not read again, so a
+/// GPU stuck in the driver keeps a single thread blocked instead of one per poll.
+fn read_nvml_gpu_parameters(
+    nvml: &Arc<Nvml>,
+    current_parameters: &[HardwareParameters],
+    concurrency: usize,
+    failure_policy: FailurePolicy,
+    in_flight: &NvmlReadsInFlight,
+) -> Result<Vec<HardwareParameters>, HardwareMonitorError> {
+    let num_of_devices = nvml.device_count()?;
+    let indices: Vec<u32> = (0..num_of_devices).collect();
+
+    // Identified up front, so devices are tracked and matched to their previous reading by uuid
+    let uuids: HashMap<u32, Option<String>> = indices
+        .iter()
+        .map(|&i| {
+            let uuid = nvml.device_by_index(i).and_then(|device| device.uuid());
+            (i, uuid.ok())
+        })
+        .collect();
+    let mut readings: HashMap<u32, HardwareParameters> = HashMap::new();
+    for batch in indices.chunks(concurrency.max(1)) {
+        let (sender, receiver) = mpsc::channel();
+        let mut started = 0;
+        for &i in batch {
+            let uuid = uuids.get(&i).cloned().flatten();
+            // A device without a uuid is tracked by its position
+            let key = uuid.clone().unwrap_or_else(|| format!("#{}", i));
+            let is_idle = in_flight
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .insert(key.clone());
+            if !is_idle {
+                warn!(target: LOG_TARGET, "GPU {} is still being read by an earlier poll", key);
+                continue;
+            }
+            let in_flight_read = InFlightRead {
+                in_flight: Arc::clone(in_flight),
+                key,
+            };
+            let nvml = Arc::clone(nvml);
+            let previous_parameters =
+                find_previous_gpu_parameters(current_parameters, uuid.as_deref(), i as usize)
+                    .cloned();
+            let sender = sender.clone();
+            std::thread::spawn(move || {
+                let _in_flight_read = in_flight_read;
+                // The receiver is gone if the poll already gave up on this device
+                drop(sender.send(read_nvml_gpu(&nvml, i, previous_parameters, failure_policy)));
+            });
+            started += 1;
+        }
+        drop(sender);
+
+        let deadline = Instant::now() + GPU_READ_TIMEOUT;
+        let mut finished = 0;
+        while finished < started {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match receiver.recv_timeout(remaining) {
+                Ok(NvmlGpuRead::Read(i, parameters)) => {
+                    readings.insert(i, parameters);
+                    finished += 1;
+                }
+                Ok(NvmlGpuRead::Failed(_)) => finished += 1,
+                Err(_) => break,
+            }
+        }
+    }
+
+    let identified_uuids: HashSet<&str> = uuids.values().flatten().map(String::as_str).collect();
+    let gpu_devices = indices
+        .iter()
+        .filter_map(|i| {
+            if let Some(parameters) = readings.remove(i) {
+                return Some(parameters);
+            }
+            warn!(target: LOG_TARGET, "GPU {} was not read, applying {:?}", i, failure_policy);
+            let previous = match uuids.get(i).and_then(Option::as_deref) {
+                Some(uuid) => {
+                    find_previous_gpu_parameters(current_parameters, Some(uuid), *i as usize)
+                }
+                // Without a uuid only the reading at the same position can be held,
+                // unless it belongs to a device that was identified this poll
+                None => current_parameters.get(*i as usize).filter(|previous| {
+                    previous
+                        .uuid
+                        .as_deref()
+                        .map_or(true, |uuid| !identified_uuids.contains(uuid))
+                }),
             };
+            failure_policy.failed_device(previous)
+        })
+        .collect();
+    Ok(gpu_devices)
+}
+
+fn read_nvml_gpu(
+    nvml: &Nvml,
+    index: u32,
+    previous_parameters: Option<HardwareParameters>,
+    failure_policy: FailurePolicy,
+) -> NvmlGpuRead {
+    match nvml.device_by_index(index) {
+        Ok(device) => NvmlGpuRead::Read(
+            index,
+            read_nvml_device_parameters(&device, previous_parameters, failure_policy),
+        ),
+        Err(e) => {
+            warn!(target: LOG_TARGET, "Failed to get GPU device {}: {}", index, e);
+            NvmlGpuRead::Failed(index)
+        }
+    }
+}
+
+fn read_nvml_device_parameters(
+    device: &Device,
+    current_parameters: Option<HardwareParameters>,
+    failure_policy: FailurePolicy,
+) -> HardwareParameters {
+    let previous = current_parameters.as_ref();
+    let current_temperature = match device.temperature(TemperatureSensor::Gpu) {
+        Ok(temperature) => temperature as f32,
+        Err(e) => {
+            debug!(target: LOG_TARGET, "Failed to read GPU temperature: {}", e);
+            failure_policy.fallback(previous.map(|p| p.current_temperature))
+        }
+    };
+    let (usage_percentage, memory_utilization) = match device.utilization_rates() {
+        Ok(utilization) => (utilization.gpu as f32, Some(utilization.memory as f32)),
+        Err(e) => {
+            debug!(target: LOG_TARGET, "Failed to read GPU usage: {}", e);
+            (
+                failure_policy.fallback(previous.map(|p| p.usage_percentage)),
+                failure_policy.fallback(previous.map(|p| p.memory_utilization)),
+            )
+        }
+    };
+    let (max_temperature, min_temperature) = session_extremes(previous, current_temperature);
+
+    let label = nvml_device_label(device, device.index().unwrap_or_default());
+    let is_integrated = is_integrated_gpu(&label);
+    // Probed on the first read only, afterwards unsupported sensors are not queried at all
+    let supported_sensors = previous
+        .and_then(|p| p.supported_sensors)
+        .unwrap_or_else(|| probe_nvml_sensor_support(device));
+    let power_draw_watts = if supported_sensors.power {
+        nvml_power_draw_watts(device)
+            .or_else(|| failure_policy.fallback(previous.map(|p| p.power_draw_watts)))
+    } else {
+        None
+    };
+    let fan_speeds_percentage = if supported_sensors.fan_speed {
+        nvml_fan_speeds(device, previous, failure_policy)
+    } else {
+        vec![]
+    };
+    let uncorrected_ecc_errors = if supported_sensors.ecc {
+        device
+            .total_ecc_errors(MemoryError::Uncorrected, EccCounter::Volatile)
+            .ok()
+            .or_else(|| failure_policy.fallback(previous.map(|p| p.uncorrected_ecc_errors)))
+    } else {
+        None
+    };
+    let slowdown_temperature = if supported_sensors.slowdown_temperature {
+        nvml_slowdown_temperature(device)
+    } else {
+        None
+    };
+    let memory = device.memory_info().ok();
+    let bar1_memory = if supported_sensors.bar1_memory {
+        device.bar1_memory_info().ok()
+    } else {
+        None
+    };
+
+    HardwareParameters {
+        label,
+        uuid: device.uuid().ok(),
+        usage_percentage,
+        current_temperature,
+        max_temperature,
+        min_temperature,
+        sockets: vec![],
+        uncorrected_ecc_errors,
+        efficiency: compute_efficiency(usage_percentage, power_draw_watts),
+        is_integrated,
+        cpu_tjmax: None,
+        slowdown_temperature,
+        connected_displays: nvml_connected_displays(device),
+        power_draw_watts,
+        tdp_watts: nvml_default_power_limit_watts(device),
+        fan_speed_percentage: fan_speeds_percentage.iter().max().copied(),
+        fan_speeds_percentage,
+        base_frequency_mhz: None,
+        current_frequency_mhz: None,
+        max_frequency_mhz: None,
+        bar1_memory_used_bytes: bar1_memory.as_ref().map(|memory| memory.used),
+        bar1_memory_total_bytes: bar1_memory.map(|memory| memory.total),
+        supported_sensors: Some(supported_sensors),
+        used_memory_mb: memory.as_ref().map(|memory| bytes_to_mb(memory.used)),
+        total_memory_mb: memory.map(|memory| bytes_to_mb(memory.total)),
+        core_usages: vec![],
+        core_clock_mhz: nvml_clock_mhz(device, Clock::Graphics),
+        memory_clock_mhz: nvml_clock_mhz(device, Clock::Memory),
+        throttle_reasons: nvml_throttle_reasons(device),
+        average_temperature: 0.0,
+        core_frequencies_mhz: vec![],
+        memory_temperature: nvml_memory_temperature(device),
+        encoder_utilization: device
+            .encoder_utilization()
+            .ok()
+            .map(|encoder| encoder.utilization as f32),
+        decoder_utilization: device
+            .decoder_utilization()
+            .ok()
+            .map(|decoder| decoder.utilization as f32),
+        vendor: GpuVendor::Nvidia,
+        max_power_watts: session_max_power(previous, power_draw_watts),
+        p_core_usage: None,
+        e_core_usage: None,
+        package_temperature: None,
+        core_temperatures: vec![],
+        pcie_rx_kb: nvml_pcie_throughput_kb(device, PcieUtilCounter::Receive),
+        pcie_tx_kb: nvml_pcie_throughput_kb(device, PcieUtilCounter::Send),
+        pcie_link_gen: device.current_pcie_link_gen().ok(),
+        pcie_link_width: device.current_pcie_link_width().ok(),
+        throttle_duration_ms: 0,
+        ccd_average_temperature: None,
+        ccd_max_temperature: None,
+        memory_utilization,
+        approaching_limit: false,
+    }
+}
+
+/// PCIe throughput over the last 20ms in KB/s, `None` where the card does not report it
+fn nvml_pcie_throughput_kb(device: &Device, counter: PcieUtilCounter) -> Option<f32> {
+    match device.pcie_throughput(counter) {
+        Ok(throughput) => Some(throughput as f32),
+        Err(e) => {
+            trace!(target: LOG_TARGET, "PCIe throughput not available: {}", e);
+            None
+        }
+    }
+}
+
+const NVML_IDLE_THROTTLE_REASON: &str = "GpuIdle";
+
+/// NVML throttle reasons and the names they are reported under
+const NVML_THROTTLE_REASONS: [(ThrottleReasons, &str); 9] = [
+    (ThrottleReasons::GPU_IDLE, NVML_IDLE_THROTTLE_REASON),
+    (
+        ThrottleReasons::APPLICATIONS_CLOCKS_SETTING,
+        "ApplicationsClocksSetting",
+    ),
+    (ThrottleReasons::SW_POWER_CAP, "SwPowerCap"),
+    (ThrottleReasons::HW_SLOWDOWN, "HwSlowdown"),
+    (ThrottleReasons::SYNC_BOOST, "SyncBoost"),
+    (ThrottleReasons::SW_THERMAL_SLOWDOWN, "SwThermalSlowdown"),
+    (ThrottleReasons::HW_THERMAL_SLOWDOWN, "HwThermalSlowdown"),
+    (
+        ThrottleReasons::HW_POWER_BRAKE_SLOWDOWN,
+        "HwPowerBrakeSlowdown",
+    ),
+    (
+        ThrottleReasons::DISPLAY_CLOCK_SETTING,
+        "DisplayClockSetting",
+    ),
+];
+
+fn nvml_throttle_reasons(device: &Device) -> Vec<String> {
+    match device.current_throttle_reasons() {
+        Ok(reasons) => NVML_THROTTLE_REASONS
+            .iter()
+            .filter(|(reason, _)| reasons.contains(*reason))
+            .map(|(_, name)| name.to_string())
+            .collect(),
+        Err(e) => {
+            debug!(target: LOG_TARGET, "Failed to read GPU throttle reasons: {}", e);
+            vec![]
+        }
+    }
+}
+
+/// `NVML_FI_DEV_MEMORY_TEMP`, the memory temperature is only exposed as a field value
+const NVML_FI_DEV_MEMORY_TEMP: u32 = 82;
+
+/// GDDR6X cards throttle on their memory temperature long before the core gets hot
+fn nvml_memory_temperature(device: &Device) -> Option<f32> {
+    let samples = device
+        .field_values_for(&[FieldId(NVML_FI_DEV_MEMORY_TEMP)])
+        .ok()?;
+    let temperature = match samples.into_iter().next()?.ok()?.value.ok()? {
+        SampleValue::F64(temperature) => temperature as f32,
+        SampleValue::U32(temperature) => temperature as f32,
+        SampleValue::U64(temperature) => temperature as f32,
+        SampleValue::I64(temperature) => temperature as f32,
+    };
+    // Cards without a memory sensor report 0 instead of an error
+    (temperature > 0.0).then_some(temperature)
+}
+
+fn nvml_clock_mhz(device: &Device, clock: Clock) -> Option<f32> {
+    device.clock_info(clock).ok().map(|mhz| mhz as f32)
+}
+
+fn bytes_to_mb(bytes: u64) -> f32 {
+    bytes as f32 / (1024.0 * 1024.0)
+}
+
+fn probe_nvml_sensor_support(device: &Device) -> SensorSupport {
+    SensorSupport {
+        power: nvml_power_draw_watts(device).is_some(),
+        fan_speed: device.num_fans().is_ok_and(|fans| fans > 0),
+        ecc: device
+            .total_ecc_errors(MemoryError::Uncorrected, EccCounter::Volatile)
+            .is_ok(),
+        slowdown_temperature: nvml_slowdown_temperature(device).is_some(),
+        bar1_memory: device.bar1_memory_info().is_ok(),
+    }
+}
+
+/// sysinfo handles kept across polls, refreshing them is much cheaper than enumerating again
+struct SysinfoCache {
+    system: Mutex<System>,
+    components: Mutex<Components>,
+}
+
+impl SysinfoCache {
+    fn new() -> Self {
+        // The first refresh also fills in the CPU brand and the component labels
+        SysinfoCache {
+            system: Mutex::new(System::new_with_specifics(
+                RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
+            )),
+            components: Mutex::new(Components::new_with_refreshed_list()),
+        }
+    }
+
+    fn system(&self) -> MutexGuard<'_, System> {
+        self.system.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn memory(&self) -> MemoryParameters {
+        let mut system = self.system();
+        system.refresh_memory();
+        MemoryParameters {
+            used_mb: bytes_to_mb(system.used_memory()),
+            total_mb: bytes_to_mb(system.total_memory()),
+            swap_used_mb: bytes_to_mb(system.used_swap()),
+            swap_total_mb: bytes_to_mb(system.total_swap()),
+        }
+    }
+
+    /// Reads the component temperatures once per poll, every section shares the readings
+    fn refresh_components(&self) {
+        self.components().refresh();
+    }
+
+    /// Components as of the last `refresh_components`
+    fn components(&self) -> MutexGuard<'_, Components> {
+        self.components
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// How often a failed NVML initialization is retried, the driver may still be loading after boot
+const NVML_INIT_RETRY_INTERVAL: Duration = Duration::from_secs(30);
+
+/// NVML access shared by the Windows and Linux backends, which read NVIDIA GPUs the same way
+struct NvmlGpuReader {
+    /// Behind a lock so a read can pick up NVML once the driver becomes ready
+    handle: Mutex<NvmlHandle>,
+    concurrency: AtomicUsize,
+    failure_policy: Mutex<FailurePolicy>,
+    /// Filled by a background thread started on the first read with NVML loaded
+    xid_errors: Arc<Mutex<HashMap<String, XidErrors>>>,
+    xid_watcher: Once,
+    reads_in_flight: NvmlReadsInFlight,
+}
+
+struct NvmlHandle {
+    nvml: Option<Arc<Nvml>>,
+    /// Kept from the last initialization attempt to tell the user why no GPU is listed
+    init_error: Option<String>,
+    last_init_attempt: Instant,
+}
+
+impl NvmlGpuReader {
+    fn new() -> Self {
+        let (nvml, init_error) = match HardwareMonitor::initialize_nvml() {
+            Ok(nvml) => (Some(nvml), None),
+            Err(e) => (None, Some(e.to_string())),
+        };
+        NvmlGpuReader {
+            handle: Mutex::new(NvmlHandle {
+                nvml,
+                init_error,
+                last_init_attempt: Instant::now(),
+            }),
+            concurrency: AtomicUsize::new(default_gpu_read_concurrency()),
+            failure_policy: Mutex::new(FailurePolicy::default()),
+            xid_errors: Arc::new(Mutex::new(HashMap::new())),
+            xid_watcher: Once::new(),
+            reads_in_flight: Arc::new(Mutex::new(HashSet::new())),
+        }
+    }
+
+    fn concurrency(&self) -> usize {
+        self.concurrency.load(Ordering::Relaxed)
+    }
+
+    fn set_concurrency(&self, concurrency: usize) {
+        self.concurrency
+            .store(concurrency.max(1), Ordering::Relaxed);
+    }
+
+    fn failure_policy(&self) -> FailurePolicy {
+        *self
+            .failure_policy
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn set_failure_policy(&self, policy: FailurePolicy) {
+        *self
+            .failure_policy
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = policy;
+    }
+
+    fn handle(&self) -> MutexGuard<'_, NvmlHandle> {
+        self.handle.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// The NVML handle, retrying a failed initialization at most once per retry interval
+    fn nvml(&self) -> Option<Arc<Nvml>> {
+        let mut handle = self.handle();
+        if handle.nvml.is_none() && handle.last_init_attempt.elapsed() >= NVML_INIT_RETRY_INTERVAL {
+            handle.last_init_attempt = Instant::now();
+            match Nvml::init() {
+                Ok(nvml) => {
+                    info!(target: LOG_TARGET, "NVML initialized after a retry");
+                    handle.nvml = Some(Arc::new(nvml));
+                    handle.init_error = None;
+                }
+                Err(e) => {
+                    debug!(target: LOG_TARGET, "Retrying NVML initialization failed: {}", e);
+                    handle.init_error = Some(e.to_string());
+                }
+            }
+        }
+        handle.nvml.clone()
+    }
+
+    fn is_loaded(&self) -> bool {
+        self.handle().nvml.is_some()
+    }
+
+    fn status(&self) -> GpuBackendStatus {
+        let handle = self.handle();
+        GpuBackendStatus {
+            nvml_available: handle.nvml.is_some(),
+            nvml_error: handle.init_error.clone(),
+        }
+    }
+
+    /// `None` when NVML could not be loaded, so the backend can fall back to another source
+    fn read_gpu_parameters(
+        &self,
+        current_parameters: &[HardwareParameters],
+    ) -> Option<Result<Vec<HardwareParameters>, HardwareMonitorError>> {
+        let nvml = self.nvml()?;
+        self.xid_watcher.call_once(|| {
+            watch_nvml_xid_errors(Arc::clone(&nvml), Arc::downgrade(&self.xid_errors));
+        });
+        Some(read_nvml_gpu_parameters(
+            &nvml,
+            current_parameters,
+            self.concurrency(),
+            self.failure_policy(),
+            &self.reads_in_flight,
+        ))
+    }
+
+    fn xid_errors(&self) -> HashMap<String, XidErrors> {
+        self.xid_errors
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    fn read_gpu_device_parameters(
+        &self,
+        uuid: &str,
+        current_parameters: Option<HardwareParameters>,
+    ) -> Option<HardwareParameters> {
+        let nvml = self.nvml()?;
+        match nvml.device_by_uuid(uuid) {
+            Ok(device) => Some(read_nvml_device_parameters(
+                &device,
+                current_parameters,
+                self.failure_policy(),
+            )),
+            Err(e) => {
+                warn!(target: LOG_TARGET, "Failed to get GPU device {}: {}", uuid, e);
+                None
+            }
+        }
+    }
+}
+
+fn log_components(components: &Components) {
+    for component in components.iter() {
+        debug!(target: LOG_TARGET, "Component: {} Temperature: {}", component.label(), component.temperature());
+    }
+}
+
+fn gpu_status_file_path(config_path: &Path) -> PathBuf {
+    config_path.join("gpuminer").join("gpu_status.json")
+}
+
+fn find_gpu_status_file(config_path: &Path) -> Option<PathBuf> {
+    let file = gpu_status_file_path(config_path);
+    if file.exists() {
+        trace!(target: LOG_TARGET, "Loading gpu status from file: {:?}", file);
+        Some(file)
+    } else {
+        debug!(target: LOG_TARGET, "Gpu status file does not exist or is corrupt");
+        None
+    }
+}
+
+/// GPU devices listed in the gpu status file written by the GPU miner
+pub(crate) fn read_gpu_status_file(file: Option<&Path>) -> Vec<GpuStatus> {
+    let Some(file_path) = file else {
+        return vec![];
+    };
+    let gpu_status_file = match fs::read_to_string(file_path) {
+        Ok(f) => f,
+        Err(e) => {
+            warn!(target: LOG_TARGET, "Failed to read gpu status file: {}", e);
+            return vec![];
+        }
+    };
+    match serde_json::from_str::<GpuStatusFile>(&gpu_status_file) {
+        Ok(gpu) => gpu.gpu_devices,
+        Err(e) => {
+            warn!(target: LOG_TARGET, "Failed to parse gpu status: {:?}", e);
+            vec![]
+        }
+    }
+}
+
+/// Writes the devices to a temporary file first, so the GPU miner never reads a partial file
+fn write_gpu_status_file(file: &Path, devices: Vec<GpuStatus>) -> Result<(), anyhow::Error> {
+    if let Some(dir) = file.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let content = serde_json::to_string_pretty(&GpuStatusFile {
+        gpu_devices: devices,
+    })?;
+    let temp_file = file.with_extension("json.tmp");
+    fs::write(&temp_file, content)
+        .map_err(|e| anyhow!("Failed to write gpu status file {:?}: {}", temp_file, e))?;
+    fs::rename(&temp_file, file)
+        .map_err(|e| anyhow!("Failed to replace gpu status file {:?}: {}", file, e))
+}
+
+struct WindowsHardwareMonitor {
+    sysinfo: SysinfoCache,
+    nvml_reader: NvmlGpuReader,
+    libre_hardware_monitor: LibreHardwareMonitorReader,
+    gpu_status_file: Mutex<Option<PathBuf>>,
+}
+impl HardwareMonitorImpl for WindowsHardwareMonitor {
+    fn get_implementation_kind(&self) -> ImplementationKind {
+        ImplementationKind::Windows
+    }
+
+    fn _log_all_components(&self) {
+        log_components(&self.sysinfo.components());
+    }
+
+    fn read_cpu_parameters(
+        &self,
+        current_parameters: Option<HardwareParameters>,
+    ) -> Result<HardwareParameters, HardwareMonitorError> {
+        let mut system = self.sysinfo.system();
+        system.refresh_cpu_all();
+        let components = self.sysinfo.components();
+        let cpu_components: Vec<&Component> = components
+            .deref()
+            .iter()
+            .filter(|c| c.label().contains("Cpu"))
+            .collect();
+
+        // sysinfo needs a ring-0 driver for CPU sensors on Windows, so a running
+        // LibreHardwareMonitor is preferred and sysinfo is only the fallback
+        let libre_hardware_monitor = self.libre_hardware_monitor.read().unwrap_or_default();
+        let cpu_temperatures: Vec<f32> = match libre_hardware_monitor.cpu_temperature {
+            Some(temperature) => vec![temperature],
+            None => cpu_components.iter().map(|c| c.temperature()).collect(),
+        };
+        let fan_speeds_percentage = libre_hardware_monitor.cpu_fan_speeds_percentage;
+
+        let avarage_temperature = average_cpu_temperature(&cpu_temperatures);
+
+        // Wait a bit because CPU usage is based on diff.
+        std::thread::sleep(cpu_usage_diff_interval());
+        system.refresh_cpu_all();
+
+        let usage = system.global_cpu_usage();
+        let core_usages: Vec<f32> = system.cpus().iter().map(|cpu| cpu.cpu_usage()).collect();
+        let label = cpu_label(system.cpus());
+
+        let current_frequency_mhz = sysinfo_cpu_frequency_mhz(&system);
+        let core_frequencies_mhz = sysinfo_core_frequencies_mhz(&system);
+        let tdp_watts = lookup_cpu_tdp_watts(&label);
+
+        let (max_temperature, min_temperature) =
+            session_extremes(current_parameters.as_ref(), avarage_temperature);
+        Ok(HardwareParameters {
+            label,
+            usage_percentage: usage,
+            current_temperature: avarage_temperature,
+            max_temperature,
+            min_temperature,
+            fan_speed_percentage: fan_speeds_percentage.iter().max().copied(),
+            fan_speeds_percentage,
+            current_frequency_mhz,
+            tdp_watts,
+            core_usages,
+            core_frequencies_mhz,
+            ..Default::default()
+        })
+    }
+    fn read_gpu_parameters(
+        &self,
+        current_parameters: Vec<HardwareParameters>,
+    ) -> Result<Vec<HardwareParameters>, HardwareMonitorError> {
+        self.nvml_reader
+            .read_gpu_parameters(&current_parameters)
+            .unwrap_or(Ok(vec![]))
+    }
+    fn read_gpu_device_parameters(
+        &self,
+        uuid: &str,
+        current_parameters: Option<HardwareParameters>,
+    ) -> Option<HardwareParameters> {
+        self.nvml_reader
+            .read_gpu_device_parameters(uuid, current_parameters)
+    }
+    fn read_motherboard_parameters(
+        &self,
+        current_parameters: Vec<HardwareParameters>,
+    ) -> Vec<HardwareParameters> {
+        let board_sensors = self
+            .libre_hardware_monitor
+            .read()
+            .map(|snapshot| snapshot.motherboard_temperatures)
+            .unwrap_or_default();
+        if board_sensors.is_empty() {
+            return read_matching_components(
+                &self.sysinfo.components(),
+                &MOTHERBOARD_SENSORS,
+                &current_parameters,
+            );
+        }
+        board_sensors
+            .into_iter()
+            .map(|sensor| {
+                let previous = current_parameters.iter().find(|p| p.label == sensor.name);
+                let (max_temperature, min_temperature) = session_extremes(previous, sensor.value);
+                HardwareParameters {
+                    label: sensor.name,
+                    current_temperature: sensor.value,
+                    max_temperature,
+                    min_temperature,
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+    fn start_poll(&self) {
+        self.sysinfo.refresh_components();
+    }
+    fn read_memory_parameters(&self) -> Option<MemoryParameters> {
+        Some(self.sysinfo.memory())
+    }
+    fn read_gpu_devices(&self) -> Vec<GpuStatus> {
+        read_gpu_status_file(
+            self.gpu_status_file
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .as_deref(),
+        )
+    }
+    fn has_gpu_sensors(&self) -> bool {
+        self.nvml_reader.is_loaded()
+    }
+    fn gpu_backend_status(&self) -> GpuBackendStatus {
+        self.nvml_reader.status()
+    }
+    fn gpu_xid_errors(&self) -> HashMap<String, XidErrors> {
+        self.nvml_reader.xid_errors()
+    }
+    fn set_gpu_read_concurrency(&self, concurrency: usize) {
+        self.nvml_reader.set_concurrency(concurrency);
+    }
+    fn gpu_read_concurrency(&self) -> Option<usize> {
+        Some(self.nvml_reader.concurrency())
+    }
+    fn set_failure_policy(&self, policy: FailurePolicy) {
+        self.nvml_reader.set_failure_policy(policy);
+    }
+    fn finish_poll(&self) {
+        self.libre_hardware_monitor.finish_poll();
+    }
+    fn load_status_file(&self, config_path: PathBuf) -> Result<(), anyhow::Error> {
+        if let Some(file) = find_gpu_status_file(&config_path) {
+            *self
+                .gpu_status_file
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner) = Some(file);
+        }
+        Ok(())
+    }
+}
+
+fn nvidia_smi_gpu_parameters(
+    gpus: Vec<nvidia_smi::NvidiaSmiGpu>,
+    current_parameters: &[HardwareParameters],
+) -> Vec<HardwareParameters> {
+    gpus.into_iter()
+        .enumerate()
+        .map(|(i, gpu)| {
+            let previous = find_previous_gpu_parameters(current_parameters, gpu.uuid.as_deref(), i);
+            let (max_temperature, min_temperature) = session_extremes(previous, gpu.temperature);
+            HardwareParameters {
+                is_integrated: is_integrated_gpu(&gpu.name),
+                label: gpu.name,
+                uuid: gpu.uuid,
+                usage_percentage: gpu.usage_percentage,
+                current_temperature: gpu.temperature,
+                max_temperature,
+                min_temperature,
+                efficiency: compute_efficiency(gpu.usage_percentage, gpu.power_draw_watts),
+                max_power_watts: session_max_power(previous, gpu.power_draw_watts),
+                power_draw_watts: gpu.power_draw_watts,
+                vendor: GpuVendor::Nvidia,
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+struct LinuxHardwareMonitor {
+    sysinfo: SysinfoCache,
+    nvml_reader: NvmlGpuReader,
+    gpu_status_file: Mutex<Option<PathBuf>>,
+    nvidia_smi_fallback: AtomicBool,
+}
+
+/// Labels of motherboard sensors, the VRM is often the hottest component on overclocked rigs
+const MOTHERBOARD_SENSORS: [&str; 4] = ["motherboard", "vrm", "pch", "chipset"];
+
+/// Labels of drive sensors, e.g. `nvme Composite` on Linux
+const DISK_SENSORS: [&str; 1] = ["nvme"];
+
+/// Reads the components whose lowercase label contains one of the sensor names
+fn read_matching_components(
+    components: &Components,
+    sensor_names: &[&str],
+    current_parameters: &[HardwareParameters],
+) -> Vec<HardwareParameters> {
+    components
+        .deref()
+        .iter()
+        .filter(|c| {
+            let label = c.label().to_lowercase();
+            sensor_names.iter().any(|sensor| label.contains(sensor))
+        })
+        .map(|c| {
+            let current_temperature = c.temperature();
+            let previous = current_parameters.iter().find(|p| p.label == c.label());
+            let (max_temperature, min_temperature) =
+                session_extremes(previous, current_temperature);
+            HardwareParameters {
+                label: c.label().to_string(),
+                current_temperature,
+                max_temperature,
+                min_temperature,
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+/// Component label fragments of the per-CCD (k10temp) and per-core (coretemp) CPU sensors
+const CPU_CORE_SENSOR_NAMES: [&str; 2] = [AMD_CCD_SENSOR_NAME, "Core "];
+/// Per-die sensors of AMD Zen CPUs, absent on older chips
+const AMD_CCD_SENSOR_NAME: &str = "Tccd";
+
+/// Thermal zone types the kernel uses for CPU sensors
+const CPU_THERMAL_ZONE_TYPES: [&str; 4] = ["x86_pkg_temp", "cpu-thermal", "cpu_thermal", "k10temp"];
+
+/// Reads the temperatures of thermal zones whose `type` identifies them as CPU sensors
+fn read_cpu_thermal_zone_temperatures() -> Vec<f32> {
+    let thermal_zones = match fs::read_dir("/sys/class/thermal") {
+        Ok(entries) => entries,
+        Err(e) => {
+            warn!(target: LOG_TARGET, "Failed to read thermal zones: {}", e);
+            return vec![];
+        }
+    };
+
+    thermal_zones
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("thermal_zone"))
+        })
+        .filter(|path| {
+            fs::read_to_string(path.join("type"))
+                .is_ok_and(|zone_type| CPU_THERMAL_ZONE_TYPES.contains(&zone_type.trim()))
+        })
+        .filter_map(|path| {
+            // Thermal zones report millidegrees Celsius
+            let temp = fs::read_to_string(path.join("temp")).ok()?;
+            temp.trim().parse::<f32>().ok().map(|temp| temp / 1000.0)
+        })
+        .collect()
+}
+
+/// PCI vendor ids in `device/vendor` of the GPUs read from sysfs, NVML covers NVIDIA
+const DRM_SYSFS_VENDORS: [(&str, GpuVendor, &str); 2] = [
+    ("0x1002", GpuVendor::Amd, "AMD GPU"),
+    ("0x8086", GpuVendor::Intel, "Intel GPU"),
+];
+
+/// Reads AMD and Intel GPUs from their drivers' sysfs files, cards exposing neither
+/// temperature nor usage are skipped
+fn read_drm_sysfs_gpus(current_parameters: &[HardwareParameters]) -> Vec<HardwareParameters> {
+    let cards = match fs::read_dir("/sys/class/drm") {
+        Ok(entries) => entries,
+        Err(e) => {
+            debug!(target: LOG_TARGET, "Failed to read DRM cards: {}", e);
+            return vec![];
+        }
+    };
+
+    let mut card_paths: Vec<PathBuf> = cards
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            // Connectors are listed as e.g. card0-DP-1 next to the cards themselves
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("card") && !name.contains('-'))
+        })
+        .collect();
+    card_paths.sort();
+
+    card_paths
+        .iter()
+        .filter_map(|card| read_drm_sysfs_gpu(card, current_parameters))
+        .collect()
+}
+
+fn read_drm_sysfs_gpu(
+    card: &Path,
+    current_parameters: &[HardwareParameters],
+) -> Option<HardwareParameters> {
+    let device = card.join("device");
+    let vendor_id = fs::read_to_string(device.join("vendor")).ok()?;
+    let (_, vendor, fallback_label) = DRM_SYSFS_VENDORS
+        .iter()
+        .find(|(id, _, _)| *id == vendor_id.trim())?;
+
+    let temperature = read_hwmon_temperature(&device);
+    // Only amdgpu reports its load, i915 and xe expose it through perf counters
+    let usage_percentage = fs::read_to_string(device.join("gpu_busy_percent"))
+        .ok()
+        .and_then(|busy| busy.trim().parse::<f32>().ok());
+    if temperature.is_none() && usage_percentage.is_none() {
+        debug!(target: LOG_TARGET, "Skipping {:?}, it exposes neither temperature nor usage", card);
+        return None;
+    }
+
+    let card_name = card.file_name()?.to_string_lossy();
+    let label = read_trimmed_sysfs_value(&device.join("product_name"))
+        .unwrap_or_else(|| format!("{} {}", fallback_label, card_name));
+    let uuid = read_trimmed_sysfs_value(&device.join("unique_id"));
+    // Merged after the NVIDIA cards, so the index based lookup would pick the wrong card
+    let previous = current_parameters.iter().find(|parameters| match &uuid {
+        Some(uuid) => parameters.uuid.as_ref() == Some(uuid),
+        None => parameters.label == label,
+    });
+    let current_temperature = temperature.unwrap_or_default();
+    let (max_temperature, min_temperature) = session_extremes(previous, current_temperature);
+
+    Some(HardwareParameters {
+        is_integrated: is_integrated_gpu(&label),
+        label,
+        uuid,
+        usage_percentage: usage_percentage.unwrap_or_default(),
+        current_temperature,
+        max_temperature,
+        min_temperature,
+        vendor: *vendor,
+        ..Default::default()
+    })
+}
+
+/// First temperature of the device's hwmon, the edge sensor on amdgpu and the package on xe
+fn read_hwmon_temperature(device: &Path) -> Option<f32> {
+    let hwmon = fs::read_dir(device.join("hwmon"))
+        .ok()?
+        .filter_map(Result::ok)
+        .next()?
+        .path();
+    let mut inputs: Vec<PathBuf> = fs::read_dir(hwmon)
+        .ok()?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("temp") && name.ends_with("_input"))
+        })
+        .collect();
+    inputs.sort();
+    inputs.iter().find_map(|input| {
+        // hwmon reports millidegrees Celsius
+        let temp = fs::read_to_string(input).ok()?;
+        temp.trim().parse::<f32>().ok().map(|temp| temp / 1000.0)
+    })
+}
+
+fn read_trimmed_sysfs_value(path: &Path) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
 
-            let current_temperature = current_gpu
-                .temperature(TemperatureSensor::Gpu)
-                .unwrap_or_default() as f32;
-            let usage_percentage = current_gpu
-                .utilization_rates()
-                .map(|e| e.gpu)
-                .unwrap_or_default() as f32;
-            let label = current_gpu.name().unwrap_or_else(|_e| "N/A".to_string());
-
-            let max_temperature = match current_parameters.get(i as usize) {
-                Some(current_parameters) => {
-                    current_parameters.max_temperature.max(current_temperature)
-                }
-                None => current_temperature,
-            };
+/// Logical CPUs of a core type of a hybrid Intel CPU, `cpu_core` or `cpu_atom`,
+/// `None` on CPUs with a single core type
+fn read_hybrid_cpu_list(core_type: &str) -> Option<Vec<usize>> {
+    let list = fs::read_to_string(Path::new("/sys/devices").join(core_type).join("cpus")).ok()?;
+    parse_cpu_list(&list)
+}
 
-            gpu_devices.push(HardwareParameters {
-                label,
-                usage_percentage,
-                current_temperature,
-                max_temperature,
-            });
+/// Parses a kernel CPU list such as `0-15,20`
+pub(crate) fn parse_cpu_list(list: &str) -> Option<Vec<usize>> {
+    let mut cpus = vec![];
+    for range in list.trim().split(',').filter(|range| !range.is_empty()) {
+        match range.split_once('-') {
+            Some((first, last)) => cpus.extend(first.parse::<usize>().ok()?..=last.parse().ok()?),
+            None => cpus.push(range.parse().ok()?),
         }
-        gpu_devices
     }
-    fn read_gpu_devices(&self) -> Vec<GpuStatus> {
-        let file = self.gpu_status_file.clone();
-        let mut gpu_devices = vec![];
+    Some(cpus)
+}
 
-        if let Some(file_path) = file {
-            let gpu_status_file = match fs::read_to_string(file_path) {
-                Ok(f) => f,
-                Err(e) => {
-                    warn!(target: LOG_TARGET, "Failed to read gpu status file: {}", e);
-                    return gpu_devices;
-                }
-            };
-            match serde_json::from_str::<GpuStatusFile>(&gpu_status_file) {
-                Ok(gpu) => gpu_devices = gpu.gpu_devices,
-                Err(e) => {
-                    warn!(target: LOG_TARGET, "Failed to parse gpu status: {:?}", e);
-                }
-            }
-        }
-        gpu_devices
-    }
-    fn load_status_file(&mut self, config_path: PathBuf) -> Result<(), anyhow::Error> {
-        let file: PathBuf = config_path.join("gpuminer").join("gpu_status.json");
-        if file.exists() {
-            self.gpu_status_file = Some(file.clone());
-            trace!(target: LOG_TARGET, "Loading gpu status from file: {:?}", file);
-        } else {
-            debug!(target: LOG_TARGET, "Gpu status file does not exist or is corrupt");
-        }
-        Ok(())
-    }
+/// Average usage of the given logical CPUs, `core_usages` is indexed by logical CPU
+fn core_group_usage(core_usages: &[f32], cpus: &[usize]) -> Option<f32> {
+    let usages: Vec<f32> = cpus
+        .iter()
+        .filter_map(|cpu| core_usages.get(*cpu).copied())
+        .collect();
+    average(&usages)
 }
 
-struct LinuxHardwareMonitor {
-    nvml: Option<Nvml>,
-    gpu_status_file: Option<PathBuf>,
+/// Reads the critical temperature (Tjmax) exposed by the coretemp driver for the first package
+fn read_cpu_tjmax() -> Option<f32> {
+    let hwmons = fs::read_dir("/sys/class/hwmon").ok()?;
+    hwmons
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .find(|path| {
+            fs::read_to_string(path.join("name")).is_ok_and(|name| name.trim() == "coretemp")
+        })
+        .and_then(|path| fs::read_to_string(path.join("temp1_crit")).ok())
+        // hwmon reports millidegrees Celsius
+        .and_then(|tjmax| tjmax.trim().parse::<f32>().ok())
+        .map(|tjmax| tjmax / 1000.0)
+}
+
+/// Long term power limit (PL1) of the first RAPL package, which defaults to the CPU's TDP
+fn read_rapl_power_limit_watts() -> Option<f32> {
+    fs::read_to_string("/sys/class/powercap/intel-rapl:0/constraint_0_power_limit_uw")
+        .ok()
+        .and_then(|microwatts| microwatts.trim().parse::<f32>().ok())
+        .map(|microwatts| microwatts / 1_000_000.0)
+}
+
+/// Reads a cpufreq attribute of the first core, reported by the kernel in kHz
+fn read_cpufreq_mhz(attribute: &str) -> Option<u32> {
+    fs::read_to_string(format!(
+        "/sys/devices/system/cpu/cpu0/cpufreq/{}",
+        attribute
+    ))
+    .ok()
+    .and_then(|khz| khz.trim().parse::<u32>().ok())
+    .map(|khz| khz / 1000)
+}
+
+/// Extracts the socket index from Linux coretemp labels such as "coretemp Package id 1"
+fn parse_package_id(label: &str) -> Option<u32> {
+    label
+        .split("Package id ")
+        .nth(1)?
+        .split_whitespace()
+        .next()?
+        .parse()
+        .ok()
 }
+
 impl HardwareMonitorImpl for LinuxHardwareMonitor {
-    fn _get_implementation_name(&self) -> String {
-        "Linux".to_string()
+    fn get_implementation_kind(&self) -> ImplementationKind {
+        ImplementationKind::Linux
     }
     fn _log_all_components(&self) {
-        let components = Components::new_with_refreshed_list();
-        for component in components.deref() {
-            println!(
-                "Component: {} Temperature: {}",
-                component.label(),
-                component.temperature()
-            );
-        }
+        log_components(&self.sysinfo.components());
     }
     fn read_cpu_parameters(
         &self,
         current_parameters: Option<HardwareParameters>,
-    ) -> HardwareParameters {
+    ) -> Result<HardwareParameters, HardwareMonitorError> {
         //TODO: Implement CPU usage for Linux
-        let mut system =
-            System::new_with_specifics(RefreshKind::new().with_cpu(CpuRefreshKind::everything()));
-        let components = Components::new_with_refreshed_list();
+        let mut system = self.sysinfo.system();
+        system.refresh_cpu_all();
+        let components = self.sysinfo.components();
 
         let intel_cpu_component: Vec<&Component> = components
             .deref()
@@ -349,166 +3896,317 @@ impl HardwareMonitorImpl for LinuxHardwareMonitor {
             .filter(|c| c.label().contains("k10temp Tctl"))
             .collect();
 
-        let available_cpu_components = if amd_cpu_component.is_empty() {
-            intel_cpu_component
+        // Every socket of a multi-socket machine reports its own "Package id N" sensor
+        let sockets: Vec<SocketTemperature> = intel_cpu_component
+            .iter()
+            .filter_map(|c| {
+                let socket_id = parse_package_id(c.label())?;
+                let current_temperature = c.temperature();
+                let max_temperature = current_parameters
+                    .as_ref()
+                    .and_then(|p| p.sockets.iter().find(|s| s.socket_id == socket_id))
+                    .map_or(current_temperature, |s| {
+                        s.max_temperature.max(current_temperature)
+                    });
+                Some(SocketTemperature {
+                    socket_id,
+                    current_temperature,
+                    max_temperature,
+                })
+            })
+            .collect();
+
+        let cpu_temperature = if amd_cpu_component.is_empty() && !sockets.is_empty() {
+            // Averaging sockets hides a single hot package, so report the hottest one
+            sockets
+                .iter()
+                .map(|s| s.current_temperature)
+                .fold(f32::MIN, f32::max)
         } else {
-            amd_cpu_component
-        };
+            let available_cpu_temperatures: Vec<f32> = if !amd_cpu_component.is_empty() {
+                amd_cpu_component.iter().map(|c| c.temperature()).collect()
+            } else if !intel_cpu_component.is_empty() {
+                intel_cpu_component
+                    .iter()
+                    .map(|c| c.temperature())
+                    .collect()
+            } else {
+                // No known sensor label matched, ask the kernel which thermal zones belong to the CPU
+                read_cpu_thermal_zone_temperatures()
+            };
 
-        let avarage_temperature = available_cpu_components
+            average_cpu_temperature(&available_cpu_temperatures)
+        };
+        // The thermal zone fallback is not a package sensor
+        let package_temperature = (!amd_cpu_component.is_empty()
+            || !intel_cpu_component.is_empty())
+        .then_some(cpu_temperature);
+        // Chiplet and core sensors, which the package or Tctl value hides on multi-CCD CPUs
+        let core_temperatures: Vec<f32> = components
+            .deref()
+            .iter()
+            .filter(|c| {
+                CPU_CORE_SENSOR_NAMES
+                    .iter()
+                    .any(|name| c.label().contains(name))
+            })
+            .map(|c| c.temperature())
+            .collect();
+        // Tctl stays the reported temperature for continuity, but it carries an offset on
+        // some Zen parts while the Tccd dies read the real silicon temperature
+        let ccd_temperatures: Vec<f32> = components
+            .deref()
             .iter()
+            .filter(|c| c.label().contains(AMD_CCD_SENSOR_NAME))
             .map(|c| c.temperature())
-            .sum::<f32>()
-            / available_cpu_components.len() as f32;
+            .collect();
+        let ccd_summary = ccd_temperature_summary(&ccd_temperatures);
 
         // Wait a bit because CPU usage is based on diff.
-        std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+        std::thread::sleep(cpu_usage_diff_interval());
         system.refresh_cpu_all();
 
         let usage = system.global_cpu_usage();
+        let core_usages: Vec<f32> = system.cpus().iter().map(|cpu| cpu.cpu_usage()).collect();
+        let p_core_usage =
+            read_hybrid_cpu_list("cpu_core").and_then(|cpus| core_group_usage(&core_usages, &cpus));
+        let e_core_usage =
+            read_hybrid_cpu_list("cpu_atom").and_then(|cpus| core_group_usage(&core_usages, &cpus));
+        let cpu_tjmax = read_cpu_tjmax();
 
-        let label: String = match system.cpus().first() {
-            Some(cpu) => cpu.brand().to_string(),
-            None => {
-                warn!("Failed to get CPU brand");
-                "N/A".to_string()
-            }
-        };
+        let label = cpu_label(system.cpus());
 
-        match current_parameters {
-            Some(current_parameters) => HardwareParameters {
-                label,
-                usage_percentage: usage,
-                current_temperature: avarage_temperature,
-                max_temperature: current_parameters.max_temperature.max(avarage_temperature),
-            },
-            None => HardwareParameters {
-                label,
-                usage_percentage: usage,
-                current_temperature: avarage_temperature,
-                max_temperature: avarage_temperature,
-            },
-        }
+        let current_frequency_mhz = sysinfo_cpu_frequency_mhz(&system);
+        let core_frequencies_mhz = sysinfo_core_frequencies_mhz(&system);
+        let tdp_watts = read_rapl_power_limit_watts().or_else(|| lookup_cpu_tdp_watts(&label));
+        let base_frequency_mhz = read_cpufreq_mhz("base_frequency");
+        let max_frequency_mhz = read_cpufreq_mhz("cpuinfo_max_freq");
+
+        let (max_temperature, min_temperature) =
+            session_extremes(current_parameters.as_ref(), cpu_temperature);
+        Ok(HardwareParameters {
+            label,
+            usage_percentage: usage,
+            current_temperature: cpu_temperature,
+            max_temperature,
+            min_temperature,
+            sockets,
+            cpu_tjmax,
+            base_frequency_mhz,
+            current_frequency_mhz,
+            max_frequency_mhz,
+            tdp_watts,
+            core_usages,
+            core_frequencies_mhz,
+            p_core_usage,
+            e_core_usage,
+            package_temperature,
+            core_temperatures,
+            ccd_average_temperature: ccd_summary.map(|(average, _)| average),
+            ccd_max_temperature: ccd_summary.map(|(_, max)| max),
+            ..Default::default()
+        })
     }
     fn read_gpu_parameters(
         &self,
         current_parameters: Vec<HardwareParameters>,
-    ) -> Vec<HardwareParameters> {
-        let mut gpu_devices: Vec<HardwareParameters> = vec![];
-        let nvml = match &self.nvml {
-            Some(nvml) => nvml,
-            None => {
-                // on linux use json file only if nvml not found
-                let gpus = self.read_gpu_devices();
-                for gpu in gpus {
-                    gpu_devices.push(HardwareParameters {
-                        label: gpu.device_name.clone(),
-                        usage_percentage: 0.0,
-                        current_temperature: 0.0,
-                        max_temperature: 0.0,
-                    });
-                }
-                return gpu_devices;
-            }
-        };
+    ) -> Result<Vec<HardwareParameters>, HardwareMonitorError> {
+        // NVML and nvidia-smi only see NVIDIA cards, AMD and Intel cards are read from sysfs
+        let sysfs_gpus = read_drm_sysfs_gpus(&current_parameters);
+        if let Some(gpu_parameters) = self.nvml_reader.read_gpu_parameters(&current_parameters) {
+            return gpu_parameters.map(|mut gpus| {
+                gpus.extend(sysfs_gpus);
+                gpus
+            });
+        }
 
-        let num_of_devices = nvml.device_count().unwrap_or_else(|e| {
-            println!("Failed to get number of GPU devices: {}", e);
-            0
-        });
-        for i in 0..num_of_devices {
-            let current_gpu = match nvml.device_by_index(i) {
-                Ok(device) => device,
-                Err(e) => {
-                    println!("Failed to get gpu devices: {}", e);
-                    continue; // skip to the next iteration
+        if self.nvidia_smi_fallback.load(Ordering::Relaxed) {
+            match nvidia_smi::read_gpus() {
+                Ok(gpus) if !gpus.is_empty() => {
+                    let mut gpu_devices = nvidia_smi_gpu_parameters(gpus, &current_parameters);
+                    gpu_devices.extend(sysfs_gpus);
+                    return Ok(gpu_devices);
                 }
-            };
-
-            let current_temperature = current_gpu
-                .temperature(TemperatureSensor::Gpu)
-                .unwrap_or_default() as f32;
-            let usage_percentage = current_gpu
-                .utilization_rates()
-                .map(|e| e.gpu)
-                .unwrap_or_default() as f32;
-            let label = current_gpu.name().unwrap_or("N/A".to_string());
-
-            let max_temperature = match current_parameters.get(i as usize) {
-                Some(current_parameters) => {
-                    current_parameters.max_temperature.max(current_temperature)
+                Ok(_) => debug!(target: LOG_TARGET, "nvidia-smi reported no GPUs"),
+                Err(e) => {
+                    debug!(target: LOG_TARGET, "Failed to read GPUs with nvidia-smi: {}", e)
                 }
-                None => current_temperature,
-            };
-
+            }
+        }
+        // on linux use json file only if nvml not found, cards read from sysfs replace their entries
+        let sysfs_vendors: HashSet<GpuVendor> = sysfs_gpus.iter().map(|gpu| gpu.vendor).collect();
+        let mut gpu_devices = sysfs_gpus;
+        let gpus = self
+            .read_gpu_devices()
+            .into_iter()
+            .filter(|gpu| !sysfs_vendors.contains(&GpuVendor::from_label(&gpu.device_name)));
+        for gpu in gpus {
             gpu_devices.push(HardwareParameters {
-                label,
-                usage_percentage,
-                current_temperature,
-                max_temperature,
+                label: gpu.device_name.clone(),
+                // The status file has no sensor readings, so there is no session minimum either
+                min_temperature: 0.0,
+                is_integrated: is_integrated_gpu(&gpu.device_name),
+                vendor: GpuVendor::from_label(&gpu.device_name),
+                ..Default::default()
             });
         }
-        gpu_devices
+        Ok(gpu_devices)
+    }
+    fn read_gpu_device_parameters(
+        &self,
+        uuid: &str,
+        current_parameters: Option<HardwareParameters>,
+    ) -> Option<HardwareParameters> {
+        self.nvml_reader
+            .read_gpu_device_parameters(uuid, current_parameters)
+    }
+    fn read_motherboard_parameters(
+        &self,
+        current_parameters: Vec<HardwareParameters>,
+    ) -> Vec<HardwareParameters> {
+        read_matching_components(
+            &self.sysinfo.components(),
+            &MOTHERBOARD_SENSORS,
+            &current_parameters,
+        )
+    }
+    fn read_disk_parameters(
+        &self,
+        current_parameters: Vec<HardwareParameters>,
+    ) -> Vec<HardwareParameters> {
+        read_matching_components(
+            &self.sysinfo.components(),
+            &DISK_SENSORS,
+            &current_parameters,
+        )
+    }
+    fn start_poll(&self) {
+        self.sysinfo.refresh_components();
+    }
+    fn read_memory_parameters(&self) -> Option<MemoryParameters> {
+        Some(self.sysinfo.memory())
     }
     fn read_gpu_devices(&self) -> Vec<GpuStatus> {
-        let file = self.gpu_status_file.clone();
-        let mut gpu_devices = vec![];
-
-        if let Some(file_path) = file {
-            let gpu_status_file = match fs::read_to_string(file_path) {
-                Ok(f) => f,
-                Err(e) => {
-                    warn!(target: LOG_TARGET, "Failed to read gpu status file: {}", e);
-                    return gpu_devices;
-                }
-            };
-            match serde_json::from_str::<GpuStatusFile>(&gpu_status_file) {
-                Ok(gpu) => {
-                    gpu_devices = gpu.gpu_devices;
-                }
-                Err(e) => {
-                    warn!(target: LOG_TARGET, "Failed to parse gpu status: {}", e.to_string());
-                }
-            }
+        read_gpu_status_file(
+            self.gpu_status_file
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .as_deref(),
+        )
+    }
+    fn has_gpu_sensors(&self) -> bool {
+        self.nvml_reader.is_loaded()
+    }
+    fn gpu_backend_status(&self) -> GpuBackendStatus {
+        self.nvml_reader.status()
+    }
+    fn gpu_xid_errors(&self) -> HashMap<String, XidErrors> {
+        self.nvml_reader.xid_errors()
+    }
+    fn set_gpu_read_concurrency(&self, concurrency: usize) {
+        self.nvml_reader.set_concurrency(concurrency);
+    }
+    fn gpu_read_concurrency(&self) -> Option<usize> {
+        Some(self.nvml_reader.concurrency())
+    }
+    fn set_failure_policy(&self, policy: FailurePolicy) {
+        self.nvml_reader.set_failure_policy(policy);
+    }
+    fn set_nvidia_smi_fallback(&self, enabled: bool) {
+        self.nvidia_smi_fallback.store(enabled, Ordering::Relaxed);
+    }
+    fn load_status_file(&self, config_path: PathBuf) -> Result<(), anyhow::Error> {
+        if let Some(file) = find_gpu_status_file(&config_path) {
+            *self
+                .gpu_status_file
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner) = Some(file);
         }
-        gpu_devices
+        Ok(())
     }
-    fn load_status_file(&mut self, config_path: PathBuf) -> Result<(), anyhow::Error> {
-        let file: PathBuf = config_path.join("gpuminer").join("gpu_status.json");
-        if file.exists() {
-            self.gpu_status_file = Some(file.clone());
-            trace!(target: LOG_TARGET, "Loading gpu status from file: {:?}", file);
+}
+
+/// Apple Silicon CPU sensors, as named by the IOKit HID temperature sensors and the SMC:
+/// - `pACC MTR Temp Sensor*`, `eACC MTR Temp Sensor*`: performance and efficiency core clusters
+/// - `PMU tdie*`: die sensors of the power management unit next to the CPU clusters
+/// - `Tp0*`, `Te0*`: SMC keys of the performance and efficiency cores
+const APPLE_SILICON_CPU_SENSORS: [&str; 5] = ["pACC MTR", "eACC MTR", "PMU tdie", "Tp0", "Te0"];
+/// Apple Silicon GPU sensors:
+/// - `GPU MTR Temp Sensor*`: IOKit HID sensors on the GPU die
+/// - `Tg0*`: SMC keys of the GPU cores
+const APPLE_SILICON_GPU_SENSORS: [&str; 2] = ["GPU MTR", "Tg0"];
+/// Labels of the single GPU a Mac reports, its sensors are averaged into one device
+const APPLE_SILICON_GPU_LABEL: &str = "Apple GPU";
+const MAC_GPU_LABEL: &str = "GPU";
+
+fn is_apple_silicon_sensor(label: &str, sensor_prefixes: &[&str]) -> bool {
+    sensor_prefixes
+        .iter()
+        .any(|prefix| label.starts_with(prefix))
+}
+
+/// Readings closer than this are treated as the same value when looking for aliased sensors
+const ALIASED_SENSOR_TOLERANCE: f32 = 0.01;
+
+fn normalize_sensor_label(label: &str) -> String {
+    label
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// macOS sometimes lists one physical sensor several times under slightly different labels,
+/// e.g. differing only in case or spacing. Keeps the first of the readings sharing a
+/// normalized label and value so aliases are not counted twice in averages.
+pub(crate) fn dedupe_aliased_sensors(readings: Vec<(String, f32)>) -> Vec<(String, f32)> {
+    let mut unique: Vec<(String, f32)> = vec![];
+    for (label, temperature) in readings {
+        let normalized = normalize_sensor_label(&label);
+        let is_alias = unique.iter().any(|(seen_label, seen_temperature)| {
+            normalize_sensor_label(seen_label) == normalized
+                && (seen_temperature - temperature).abs() < ALIASED_SENSOR_TOLERANCE
+        });
+        if is_alias {
+            trace!(target: LOG_TARGET, "Skipping aliased sensor {}", label);
         } else {
-            debug!(target: LOG_TARGET, "Gpu status file does not exist or is corrupt");
+            unique.push((label, temperature));
         }
-        Ok(())
     }
+    unique
+}
+
+/// Temperatures of the components with aliased sensors left out
+fn unique_component_temperatures(components: &[&Component]) -> Vec<f32> {
+    dedupe_aliased_sensors(
+        components
+            .iter()
+            .map(|c| (c.label().to_string(), c.temperature()))
+            .collect(),
+    )
+    .into_iter()
+    .map(|(_, temperature)| temperature)
+    .collect()
 }
 
 struct MacOSHardwareMonitor {
-    gpu_status_file: Option<PathBuf>,
+    sysinfo: SysinfoCache,
+    gpu_status_file: Mutex<Option<PathBuf>>,
 }
 impl HardwareMonitorImpl for MacOSHardwareMonitor {
-    fn _get_implementation_name(&self) -> String {
-        "MacOS".to_string()
+    fn get_implementation_kind(&self) -> ImplementationKind {
+        ImplementationKind::MacOS
     }
     fn _log_all_components(&self) {
-        let components = Components::new_with_refreshed_list();
-        for component in components.deref() {
-            println!(
-                "Component: {} Temperature: {}",
-                component.label(),
-                component.temperature()
-            );
-        }
+        log_components(&self.sysinfo.components());
     }
     fn read_cpu_parameters(
         &self,
         current_parameters: Option<HardwareParameters>,
-    ) -> HardwareParameters {
-        let mut system =
-            System::new_with_specifics(RefreshKind::new().with_cpu(CpuRefreshKind::everything()));
-        let components = Components::new_with_refreshed_list();
+    ) -> Result<HardwareParameters, HardwareMonitorError> {
+        let mut system = self.sysinfo.system();
+        system.refresh_cpu_all();
+        let components = self.sysinfo.components();
 
         let intel_cpu_components: Vec<&Component> = components
             .deref()
@@ -518,7 +4216,7 @@ impl HardwareMonitorImpl for MacOSHardwareMonitor {
         let silicon_cpu_components: Vec<&Component> = components
             .deref()
             .iter()
-            .filter(|c| c.label().contains("MTR"))
+            .filter(|c| is_apple_silicon_sensor(c.label(), &APPLE_SILICON_CPU_SENSORS))
             .collect();
 
         let available_cpu_components = if silicon_cpu_components.is_empty() {
@@ -527,118 +4225,417 @@ impl HardwareMonitorImpl for MacOSHardwareMonitor {
             silicon_cpu_components
         };
 
-        let avarage_temperature = available_cpu_components
-            .iter()
-            .map(|c| c.temperature())
-            .sum::<f32>()
-            / available_cpu_components.len() as f32;
+        let cpu_temperatures = unique_component_temperatures(&available_cpu_components);
+        let avarage_temperature = average_cpu_temperature(&cpu_temperatures);
 
         // Wait a bit because CPU usage is based on diff.
-        std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+        std::thread::sleep(cpu_usage_diff_interval());
         system.refresh_cpu_all();
 
         let usage = system.global_cpu_usage();
+        let core_usages: Vec<f32> = system.cpus().iter().map(|cpu| cpu.cpu_usage()).collect();
         let label: String = match system.cpus().first() {
             Some(cpu) => cpu.brand().to_string() + " CPU",
-            None => {
-                warn!("Failed to get CPU brand");
-                "N/A".to_string()
-            }
+            None => cpu_label(&[]),
         };
 
-        match current_parameters {
-            Some(current_parameters) => HardwareParameters {
-                label,
-                usage_percentage: usage,
-                current_temperature: avarage_temperature,
-                max_temperature: current_parameters.max_temperature.max(avarage_temperature),
-            },
-            None => HardwareParameters {
-                label,
-                usage_percentage: usage,
-                current_temperature: avarage_temperature,
-                max_temperature: avarage_temperature,
-            },
-        }
+        let current_frequency_mhz = sysinfo_cpu_frequency_mhz(&system);
+        let core_frequencies_mhz = sysinfo_core_frequencies_mhz(&system);
+        let tdp_watts = lookup_cpu_tdp_watts(&label);
+
+        let (max_temperature, min_temperature) =
+            session_extremes(current_parameters.as_ref(), avarage_temperature);
+        Ok(HardwareParameters {
+            label,
+            usage_percentage: usage,
+            current_temperature: avarage_temperature,
+            max_temperature,
+            min_temperature,
+            current_frequency_mhz,
+            tdp_watts,
+            core_usages,
+            core_frequencies_mhz,
+            ..Default::default()
+        })
     }
     fn read_gpu_parameters(
         &self,
         current_parameters: Vec<HardwareParameters>,
-    ) -> Vec<HardwareParameters> {
-        let mut gpu_params = vec![];
-        // GPU devices list taken from gpu_status.json file
-        let gpu_devices = self.read_gpu_devices();
-        let num_of_devices = gpu_devices.len();
-
-        let system = System::new_all();
-        let components = Components::new_with_refreshed_list();
-        let gpu_components: Vec<&Component> = components
+    ) -> Result<Vec<HardwareParameters>, HardwareMonitorError> {
+        let components = self.sysinfo.components();
+        let silicon_gpu_components: Vec<&Component> = components
             .deref()
             .iter()
-            .filter(|c| c.label().contains("GPU"))
+            .filter(|c| is_apple_silicon_sensor(c.label(), &APPLE_SILICON_GPU_SENSORS))
             .collect();
-        let avarage_temperature =
-            gpu_components.iter().map(|c| c.temperature()).sum::<f32>() / num_of_devices as f32;
+        let is_apple_silicon = !silicon_gpu_components.is_empty();
+        let gpu_components: Vec<&Component> = if is_apple_silicon {
+            silicon_gpu_components
+        } else {
+            components
+                .deref()
+                .iter()
+                .filter(|c| c.label().contains("GPU"))
+                .collect()
+        };
+        let temperatures: Vec<f32> = dedupe_aliased_sensors(
+            gpu_components
+                .iter()
+                .map(|c| (c.label().to_string(), c.temperature()))
+                .collect(),
+        )
+        .into_iter()
+        .map(|(_, temperature)| temperature)
+        .collect();
+        // Every GPU sensor belongs to the one GPU, the labels do not tell apart
+        // the integrated and discrete GPUs of the Intel Macs that have both
+        let Some(current_temperature) = average(&temperatures) else {
+            return Ok(vec![]);
+        };
 
-        for i in 0..num_of_devices {
-            let current_gpu = if let Some(device) = gpu_devices.get(i) {
-                device
-            } else {
-                println!("Failed to get GPU device nr {:?}", i);
-                continue; // skip to the next iteration
-            };
+        // Utilization is read per IOAccelerator, it is only known to be this GPU's
+        // when there is a single accelerator
+        let usage_percentage = match ioreg::read_gpu_utilization() {
+            Ok(utilization) => match utilization.as_slice() {
+                [usage] => *usage,
+                _ => 0.0,
+            },
+            Err(e) => {
+                debug!(target: LOG_TARGET, "Failed to read GPU utilization with ioreg: {}", e);
+                0.0
+            }
+        };
+
+        let label = if is_apple_silicon {
+            APPLE_SILICON_GPU_LABEL
+        } else {
+            MAC_GPU_LABEL
+        };
+        let previous = current_parameters.iter().find(|p| p.label == label);
+        let (max_temperature, min_temperature) = session_extremes(previous, current_temperature);
+        Ok(vec![HardwareParameters {
+            label: label.to_string(),
+            usage_percentage,
+            current_temperature,
+            max_temperature,
+            min_temperature,
+            // The Apple Silicon GPU shares the SoC and memory with the CPU
+            is_integrated: is_apple_silicon,
+            vendor: GpuVendor::from_label(label),
+            ..Default::default()
+        }])
+    }
+    fn read_gpu_device_parameters(
+        &self,
+        _uuid: &str,
+        _current_parameters: Option<HardwareParameters>,
+    ) -> Option<HardwareParameters> {
+        // GPU devices on MacOS are not identified by uuid
+        None
+    }
+    fn read_motherboard_parameters(
+        &self,
+        current_parameters: Vec<HardwareParameters>,
+    ) -> Vec<HardwareParameters> {
+        read_matching_components(
+            &self.sysinfo.components(),
+            &MOTHERBOARD_SENSORS,
+            &current_parameters,
+        )
+    }
+    fn read_disk_parameters(
+        &self,
+        current_parameters: Vec<HardwareParameters>,
+    ) -> Vec<HardwareParameters> {
+        read_matching_components(
+            &self.sysinfo.components(),
+            &DISK_SENSORS,
+            &current_parameters,
+        )
+    }
+    fn start_poll(&self) {
+        self.sysinfo.refresh_components();
+    }
+    fn read_memory_parameters(&self) -> Option<MemoryParameters> {
+        Some(self.sysinfo.memory())
+    }
+    fn read_gpu_devices(&self) -> Vec<GpuStatus> {
+        read_gpu_status_file(
+            self.gpu_status_file
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .as_deref(),
+        )
+    }
+    fn has_gpu_sensors(&self) -> bool {
+        true
+    }
+    fn set_gpu_read_concurrency(&self, _concurrency: usize) {
+        // GPUs are not read through NVML, so there is nothing to parallelize
+    }
+    fn gpu_read_concurrency(&self) -> Option<usize> {
+        None
+    }
+    fn set_failure_policy(&self, _policy: FailurePolicy) {
+        // GPUs are not read through NVML, so there is no failing read to handle
+    }
+    fn load_status_file(&self, config_path: PathBuf) -> Result<(), anyhow::Error> {
+        if let Some(file) = find_gpu_status_file(&config_path) {
+            *self
+                .gpu_status_file
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner) = Some(file);
+        }
+        Ok(())
+    }
+}
 
-            //TODO: Implement GPU usage for MacOS
-            let usage_percentage = system.global_cpu_usage();
-            let label: String = current_gpu.device_name.clone();
-            let mut current_temperature = avarage_temperature;
-            let mut max_temperature = avarage_temperature;
+/// Component label fragments of CPU sensors across the platforms sysinfo supports
+const FALLBACK_CPU_SENSOR_NAMES: [&str; 3] = ["cpu", "package", "core"];
 
-            if let Some(current_parameters) = current_parameters.get(i) {
-                current_temperature = current_parameters.current_temperature;
-                max_temperature = current_parameters.max_temperature.max(avarage_temperature)
-            };
+/// Best-effort backend for operating systems without a dedicated one, e.g. FreeBSD.
+/// Reads the CPU through the cross-platform sysinfo components and reports no GPUs.
+struct FallbackHardwareMonitor {
+    sysinfo: SysinfoCache,
+}
 
-            gpu_params.push(HardwareParameters {
-                label,
-                usage_percentage,
-                current_temperature,
-                max_temperature,
-            });
-        }
-        gpu_params
+impl HardwareMonitorImpl for FallbackHardwareMonitor {
+    fn get_implementation_kind(&self) -> ImplementationKind {
+        ImplementationKind::Fallback
+    }
+    fn _log_all_components(&self) {
+        log_components(&self.sysinfo.components());
+    }
+    fn read_cpu_parameters(
+        &self,
+        current_parameters: Option<HardwareParameters>,
+    ) -> Result<HardwareParameters, HardwareMonitorError> {
+        let mut system = self.sysinfo.system();
+        system.refresh_cpu_all();
+        let components = self.sysinfo.components();
+        let cpu_components: Vec<&Component> = components
+            .deref()
+            .iter()
+            .filter(|c| {
+                let label = c.label().to_lowercase();
+                FALLBACK_CPU_SENSOR_NAMES
+                    .iter()
+                    .any(|name| label.contains(name))
+            })
+            .collect();
+        let current_temperature =
+            average_cpu_temperature(&unique_component_temperatures(&cpu_components));
+
+        // Wait a bit because CPU usage is based on diff.
+        std::thread::sleep(cpu_usage_diff_interval());
+        system.refresh_cpu_all();
+
+        let (max_temperature, min_temperature) =
+            session_extremes(current_parameters.as_ref(), current_temperature);
+        Ok(HardwareParameters {
+            label: cpu_label(system.cpus()),
+            usage_percentage: system.global_cpu_usage(),
+            current_temperature,
+            max_temperature,
+            min_temperature,
+            current_frequency_mhz: sysinfo_cpu_frequency_mhz(&system),
+            core_usages: system.cpus().iter().map(|cpu| cpu.cpu_usage()).collect(),
+            core_frequencies_mhz: sysinfo_core_frequencies_mhz(&system),
+            ..Default::default()
+        })
+    }
+    fn read_gpu_parameters(
+        &self,
+        _current_parameters: Vec<HardwareParameters>,
+    ) -> Result<Vec<HardwareParameters>, HardwareMonitorError> {
+        Ok(vec![])
+    }
+    fn read_gpu_device_parameters(
+        &self,
+        _uuid: &str,
+        _current_parameters: Option<HardwareParameters>,
+    ) -> Option<HardwareParameters> {
+        None
+    }
+    fn read_motherboard_parameters(
+        &self,
+        current_parameters: Vec<HardwareParameters>,
+    ) -> Vec<HardwareParameters> {
+        read_matching_components(
+            &self.sysinfo.components(),
+            &MOTHERBOARD_SENSORS,
+            &current_parameters,
+        )
+    }
+    fn read_disk_parameters(
+        &self,
+        current_parameters: Vec<HardwareParameters>,
+    ) -> Vec<HardwareParameters> {
+        read_matching_components(
+            &self.sysinfo.components(),
+            &DISK_SENSORS,
+            &current_parameters,
+        )
+    }
+    fn start_poll(&self) {
+        self.sysinfo.refresh_components();
+    }
+    fn read_memory_parameters(&self) -> Option<MemoryParameters> {
+        Some(self.sysinfo.memory())
     }
     fn read_gpu_devices(&self) -> Vec<GpuStatus> {
-        let file = self.gpu_status_file.clone();
-        let mut gpu_devices = vec![];
+        vec![]
+    }
+    fn has_gpu_sensors(&self) -> bool {
+        false
+    }
+    fn set_gpu_read_concurrency(&self, _concurrency: usize) {
+        // GPUs are not read through NVML, so there is nothing to parallelize
+    }
+    fn gpu_read_concurrency(&self) -> Option<usize> {
+        None
+    }
+    fn set_failure_policy(&self, _policy: FailurePolicy) {
+        // GPUs are not read through NVML, so there is no failing read to handle
+    }
+    fn load_status_file(&self, _config_path: PathBuf) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+}
 
-        if let Some(file_path) = file {
-            let gpu_status_file = match fs::read_to_string(file_path) {
-                Ok(f) => f,
-                Err(e) => {
-                    warn!(target: LOG_TARGET, "Failed to read gpu status file: {}", e);
-                    return gpu_devices;
-                }
-            };
-            match serde_json::from_str::<GpuStatusFile>(&gpu_status_file) {
-                Ok(gpu) => {
-                    gpu_devices = gpu.gpu_devices;
-                }
-                Err(e) => {
-                    warn!(target: LOG_TARGET, "Failed to parse gpu status: {:?}", e);
-                }
-            }
+/// Plays back a recorded log of `HardwareStatus` rows, e.g. one exported by a user reporting an issue
+struct ReplayHardwareMonitor {
+    rows: Vec<HardwareStatus>,
+    position: AtomicUsize,
+    loop_replay: bool,
+}
+
+impl ReplayHardwareMonitor {
+    fn load(file: PathBuf, loop_replay: bool) -> Result<Self, anyhow::Error> {
+        let content = fs::read_to_string(&file)
+            .map_err(|e| anyhow!("Failed to read replay file {:?}: {}", file, e))?;
+        // Exported JSON statuses or a log written by the CSV logger
+        let rows: Vec<HardwareStatus> = if file.extension().is_some_and(|ext| ext == "csv") {
+            hardware_csv_logger::parse_csv_log(&content)
+        } else {
+            serde_json::from_str(&content).map_err(anyhow::Error::from)
+        }
+        .map_err(|e| anyhow!("Failed to parse replay file {:?}: {}", file, e))?;
+        // Readings are tracked in Celsius, whatever unit the session was recorded in
+        let rows: Vec<HardwareStatus> =
+            rows.into_iter().map(HardwareStatus::into_celsius).collect();
+        if rows.is_empty() {
+            return Err(anyhow!("Replay file {:?} contains no rows", file));
         }
-        gpu_devices
+
+        Ok(ReplayHardwareMonitor {
+            rows,
+            position: AtomicUsize::new(0),
+            loop_replay,
+        })
+    }
+
+    /// `None` once a replay that does not loop has played its last row
+    fn current_row(&self) -> Option<&HardwareStatus> {
+        self.rows.get(self.position.load(Ordering::SeqCst))
+    }
+}
+
+impl HardwareMonitorImpl for ReplayHardwareMonitor {
+    fn get_implementation_kind(&self) -> ImplementationKind {
+        ImplementationKind::Replay
+    }
+    fn _log_all_components(&self) {
+        debug!(target: LOG_TARGET, "Replaying row {} of {}", self.position.load(Ordering::SeqCst) + 1, self.rows.len());
+    }
+    fn read_cpu_parameters(
+        &self,
+        _current_parameters: Option<HardwareParameters>,
+    ) -> Result<HardwareParameters, HardwareMonitorError> {
+        self.current_row()
+            .ok_or(HardwareMonitorError::ReplayFinished)?
+            .cpu
+            .clone()
+            .ok_or(HardwareMonitorError::ReplayMissingCpu)
+    }
+    fn read_gpu_parameters(
+        &self,
+        _current_parameters: Vec<HardwareParameters>,
+    ) -> Result<Vec<HardwareParameters>, HardwareMonitorError> {
+        Ok(self
+            .current_row()
+            .map(|row| row.gpu.clone())
+            .unwrap_or_default())
+    }
+    fn read_gpu_device_parameters(
+        &self,
+        uuid: &str,
+        _current_parameters: Option<HardwareParameters>,
+    ) -> Option<HardwareParameters> {
+        self.current_row()?
+            .gpu
+            .iter()
+            .find(|gpu| gpu.uuid.as_deref() == Some(uuid))
+            .cloned()
+    }
+    fn read_motherboard_parameters(
+        &self,
+        _current_parameters: Vec<HardwareParameters>,
+    ) -> Vec<HardwareParameters> {
+        self.current_row()
+            .map(|row| row.motherboard.clone())
+            .unwrap_or_default()
     }
-    fn load_status_file(&mut self, config_path: PathBuf) -> Result<(), anyhow::Error> {
-        let file: PathBuf = config_path.join("gpuminer").join("gpu_status.json");
-        if file.exists() {
-            self.gpu_status_file = Some(file.clone());
-            trace!(target: LOG_TARGET, "Loading gpu status from file: {:?}", file);
+    fn read_disk_parameters(
+        &self,
+        _current_parameters: Vec<HardwareParameters>,
+    ) -> Vec<HardwareParameters> {
+        self.current_row()
+            .map(|row| row.disks.clone())
+            .unwrap_or_default()
+    }
+    fn read_memory_parameters(&self) -> Option<MemoryParameters> {
+        self.current_row().and_then(|row| row.memory)
+    }
+    fn read_power_status(&self) -> Option<PowerStatus> {
+        let row = self.current_row()?;
+        Some(PowerStatus {
+            power_source: row.power_source?,
+            battery_percentage: row.battery_percentage,
+        })
+    }
+    fn finish_poll(&self) {
+        let position = self.position.load(Ordering::SeqCst);
+        let next_position = if position + 1 < self.rows.len() {
+            position + 1
+        } else if self.loop_replay {
+            0
         } else {
-            debug!(target: LOG_TARGET, "Gpu status file does not exist or is corrupt");
-        }
+            if position < self.rows.len() {
+                info!(target: LOG_TARGET, "Replay finished after {} rows", self.rows.len());
+            }
+            // Past the last row, so every further read reports the sensors unavailable
+            self.rows.len()
+        };
+        self.position.store(next_position, Ordering::SeqCst);
+    }
+    fn read_gpu_devices(&self) -> Vec<GpuStatus> {
+        vec![]
+    }
+    fn has_gpu_sensors(&self) -> bool {
+        self.current_row()
+            .map_or(false, |row| row.gpu_availability == Availability::Available)
+    }
+    fn set_gpu_read_concurrency(&self, _concurrency: usize) {
+        // GPUs are not read through NVML, so there is nothing to parallelize
+    }
+    fn gpu_read_concurrency(&self) -> Option<usize> {
+        None
+    }
+    fn set_failure_policy(&self, _policy: FailurePolicy) {
+        // GPUs are not read through NVML, so there is no failing read to handle
+    }
+    fn load_status_file(&self, _config_path: PathBuf) -> Result<(), anyhow::Error> {
         Ok(())
     }
 }
@@ -0,0 +1,29 @@
+use std::process::Command;
+
+use anyhow::anyhow;
+
+const IOREG_PATH: &str = "/usr/sbin/ioreg";
+const DEVICE_UTILIZATION_KEY: &str = "\"Device Utilization %\"=";
+
+/// Reads the load of every GPU from the performance statistics IOKit keeps per `IOAccelerator`
+pub fn read_gpu_utilization() -> Result<Vec<f32>, anyhow::Error> {
+    let output = Command::new(IOREG_PATH)
+        .args(["-r", "-d", "1", "-c", "IOAccelerator"])
+        .output()?;
+    if !output.status.success() {
+        return Err(anyhow!("ioreg exited with {}", output.status));
+    }
+    Ok(parse_output(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Finds `"Device Utilization %"=<n>` in the `PerformanceStatistics` of each accelerator
+pub(crate) fn parse_output(output: &str) -> Vec<f32> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (_, value) = line.split_once(DEVICE_UTILIZATION_KEY)?;
+            let digits: String = value.chars().take_while(char::is_ascii_digit).collect();
+            digits.parse().ok()
+        })
+        .collect()
+}
@@ -0,0 +1,181 @@
+use std::{
+    process::Command,
+    sync::{Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+use anyhow::anyhow;
+use log::debug;
+use serde::Deserialize;
+
+const LOG_TARGET: &str = "tari::universe::libre_hardware_monitor";
+/// Sensors LibreHardwareMonitor publishes to WMI while it runs, always as a JSON array
+const WMI_SENSOR_QUERY: &str = "ConvertTo-Json -Compress -InputObject @(Get-CimInstance \
+    -Namespace root/LibreHardwareMonitor -ClassName Sensor \
+    | Select-Object Name, Identifier, SensorType, Value)";
+/// Wait before querying again once LibreHardwareMonitor was not found, it is rarely started later
+const PROBE_RETRY_INTERVAL: Duration = Duration::from_secs(60);
+/// Sensors that report the temperature of the whole CPU rather than a single core
+const CPU_PACKAGE_SENSORS: [&str; 2] = ["CPU Package", "Core (Tctl/Tdie)"];
+/// Sensor id prefixes of the CPU and of the motherboard's Super I/O chip
+const CPU_HARDWARE: [&str; 2] = ["/intelcpu/", "/amdcpu/"];
+const MOTHERBOARD_HARDWARE: &str = "/lpc/";
+const TEMPERATURE_SENSOR: &str = "/temperature/";
+const FAN_CONTROL_SENSOR: &str = "/control/";
+/// WMI sensor types kept, fan sensors report RPM while controls report the duty cycle
+const SENSOR_TYPES: [&str; 2] = ["Temperature", "Control"];
+
+/// Instance of the `Sensor` class in the `root/LibreHardwareMonitor` WMI namespace
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct WmiSensor {
+    name: String,
+    identifier: String,
+    sensor_type: String,
+    value: Option<f32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LibreHardwareMonitorSensor {
+    pub name: String,
+    pub sensor_id: String,
+    pub value: f32,
+}
+
+/// The sensors of one LibreHardwareMonitor read, split by the device they belong to
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LibreHardwareMonitorSnapshot {
+    /// CPU package temperature, or the average of all CPU sensors when no package sensor exists
+    pub cpu_temperature: Option<f32>,
+    /// Duty cycle of the fans whose name marks them as CPU fans
+    pub cpu_fan_speeds_percentage: Vec<u32>,
+    /// Temperature sensors of the motherboard's Super I/O chip
+    pub motherboard_temperatures: Vec<LibreHardwareMonitorSensor>,
+}
+
+/// Reads LibreHardwareMonitor at most once per poll, the CPU and motherboard reads share it
+#[derive(Default)]
+pub struct LibreHardwareMonitorReader {
+    snapshot: Mutex<Option<Option<LibreHardwareMonitorSnapshot>>>,
+    /// When the last query found no running instance
+    unavailable_since: Mutex<Option<Instant>>,
+}
+
+impl LibreHardwareMonitorReader {
+    /// The CPU and motherboard sensors of a running LibreHardwareMonitor instance,
+    /// `None` when it is not running or was not found within the retry interval
+    pub fn read(&self) -> Option<LibreHardwareMonitorSnapshot> {
+        self.snapshot
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get_or_insert_with(|| self.probe())
+            .clone()
+    }
+
+    fn probe(&self) -> Option<LibreHardwareMonitorSnapshot> {
+        let mut unavailable_since = self
+            .unavailable_since
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if unavailable_since.is_some_and(|since| since.elapsed() < PROBE_RETRY_INTERVAL) {
+            return None;
+        }
+        let snapshot = read_snapshot();
+        *unavailable_since = snapshot.is_none().then(Instant::now);
+        snapshot
+    }
+
+    /// Drops the snapshot of the finished poll, so the next poll reads the sensors again
+    pub fn finish_poll(&self) {
+        *self.snapshot.lock().unwrap_or_else(PoisonError::into_inner) = None;
+    }
+}
+
+fn read_snapshot() -> Option<LibreHardwareMonitorSnapshot> {
+    match read_sensors() {
+        Ok(sensors) => Some(snapshot(&sensors)),
+        Err(e) => {
+            debug!(target: LOG_TARGET, "LibreHardwareMonitor is not available: {}", e);
+            None
+        }
+    }
+}
+
+fn read_sensors() -> Result<Vec<LibreHardwareMonitorSensor>, anyhow::Error> {
+    let mut command = Command::new("powershell");
+    command.args([
+        "-NoProfile",
+        "-NonInteractive",
+        "-Command",
+        WMI_SENSOR_QUERY,
+    ]);
+    #[cfg(target_os = "windows")]
+    {
+        use crate::consts::PROCESS_CREATION_NO_WINDOW;
+        use std::os::windows::process::CommandExt;
+        command.creation_flags(PROCESS_CREATION_NO_WINDOW);
+    }
+    let output = command.output()?;
+    if !output.status.success() {
+        return Err(anyhow!("WMI query exited with {}", output.status));
+    }
+    parse_sensors(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Keeps the temperature and fan control sensors of the WMI query output
+pub(crate) fn parse_sensors(
+    output: &str,
+) -> Result<Vec<LibreHardwareMonitorSensor>, anyhow::Error> {
+    let sensors: Vec<WmiSensor> = serde_json::from_str(output.trim())?;
+    Ok(sensors
+        .into_iter()
+        .filter(|sensor| SENSOR_TYPES.contains(&sensor.sensor_type.as_str()))
+        .filter_map(|sensor| {
+            Some(LibreHardwareMonitorSensor {
+                value: sensor.value?,
+                name: sensor.name,
+                sensor_id: sensor.identifier,
+            })
+        })
+        .collect())
+}
+
+pub(crate) fn snapshot(sensors: &[LibreHardwareMonitorSensor]) -> LibreHardwareMonitorSnapshot {
+    let cpu_sensors: Vec<&LibreHardwareMonitorSensor> = sensors
+        .iter()
+        .filter(|s| CPU_HARDWARE.iter().any(|cpu| s.sensor_id.starts_with(cpu)))
+        .filter(|s| s.sensor_id.contains(TEMPERATURE_SENSOR))
+        .collect();
+    let cpu_temperature = cpu_sensors
+        .iter()
+        .find(|s| CPU_PACKAGE_SENSORS.contains(&s.name.as_str()))
+        .map(|package| package.value)
+        .or_else(|| {
+            (!cpu_sensors.is_empty()).then(|| {
+                cpu_sensors.iter().map(|s| s.value).sum::<f32>() / cpu_sensors.len() as f32
+            })
+        });
+
+    let board_sensors = sensors
+        .iter()
+        .filter(|s| s.sensor_id.starts_with(MOTHERBOARD_HARDWARE));
+    LibreHardwareMonitorSnapshot {
+        cpu_temperature,
+        // Fan controls report the duty cycle in percent, fan sensors the speed in RPM
+        cpu_fan_speeds_percentage: board_sensors
+            .clone()
+            .filter(|s| s.sensor_id.contains(FAN_CONTROL_SENSOR) && s.name.contains("CPU"))
+            .map(|s| duty_cycle_percentage(s.value))
+            .collect(),
+        motherboard_temperatures: board_sensors
+            .filter(|s| s.sensor_id.contains(TEMPERATURE_SENSOR))
+            .cloned()
+            .collect(),
+    }
+}
+
+/// Clamped to 0-100 first, so the cast cannot truncate
+#[allow(clippy::cast_possible_truncation)]
+fn duty_cycle_percentage(value: f32) -> u32 {
+    value.round().clamp(0.0, 100.0) as u32
+}
@@ -8,7 +8,9 @@ use log::{debug, error, info, warn};
 use sentry::protocol::Event;
 use sentry_tauri::sentry;
 use serde::Serialize;
+use std::collections::HashMap;
 use std::fs::{read_dir, remove_dir_all, remove_file};
+use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::thread::sleep;
@@ -19,6 +21,7 @@ use tari_core::transactions::tari_amount::MicroMinotari;
 use tari_shutdown::Shutdown;
 use tauri::async_runtime::block_on;
 use tauri::{Manager, RunEvent, UpdaterEvent};
+use tokio::sync::broadcast::error::RecvError;
 use tokio::sync::RwLock;
 use wallet_adapter::TransactionInfo;
 
@@ -26,7 +29,12 @@ use app_config::AppConfig;
 use app_in_memory_config::{AirdropInMemoryConfig, AppInMemoryConfig};
 use binaries::{binaries_list::Binaries, binaries_resolver::BinaryResolver};
 use gpu_miner_adapter::{GpuMinerStatus, GpuNodeSource};
-use hardware_monitor::{HardwareMonitor, HardwareParameters};
+use hardware_monitor::{
+    AggregatedStatus, Availability, ComponentKind, FailurePolicy, GpuBackendStatus, GpuStatus,
+    GpuStatusTransition, GpuUptime, GpuVendor, HardwareMonitor, HardwareParameters, HardwareStatus,
+    HardwareStatusDelta, HistorySample, ImplementationKind, PlausibilityThresholds, ReadingSource,
+    TemperatureUnit, ThermalThresholds,
+};
 use node_manager::NodeManagerError;
 use progress_tracker::ProgressTracker;
 use setup_status_event::SetupStatusEvent;
@@ -60,21 +68,27 @@ mod format_utils;
 mod github;
 mod gpu_miner;
 mod gpu_miner_adapter;
+mod hardware_csv_logger;
 mod hardware_monitor;
 mod internal_wallet;
+mod ioreg;
+mod libre_hardware_monitor;
 mod mm_proxy_adapter;
 mod mm_proxy_manager;
 mod network_utils;
 mod node_adapter;
 mod node_manager;
+mod nvidia_smi;
 mod p2pool;
 mod p2pool_adapter;
 mod p2pool_manager;
+mod power_supply;
 mod process_adapter;
 mod process_killer;
 mod process_utils;
 mod process_watcher;
 mod progress_tracker;
+mod prometheus_metrics;
 mod setup_status_event;
 mod systemtray_manager;
 mod telemetry_manager;
@@ -102,6 +116,25 @@ const APPLICATION_FOLDER_ID: &str = "com.tari.universe";
 #[cfg(all(feature = "release-ci-beta", not(feature = "release-ci")))]
 const APPLICATION_FOLDER_ID: &str = "com.tari.universe.beta";
 
+#[derive(Debug, Serialize, Clone)]
+struct GpuEccErrorPayload {
+    uuid: String,
+    count: u64,
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct NewTemperaturePeakPayload {
+    label: String,
+    max_temperature: f32,
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct TemperatureAlertPayload {
+    uuid: String,
+    temperature: f32,
+    threshold: f32,
+}
+
 #[derive(Debug, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
 struct UpdateProgressRustEvent {
@@ -950,6 +983,400 @@ async fn set_excluded_gpu_devices(
     Ok(())
 }
 
+#[tauri::command]
+async fn set_gpu_device_priority(
+    uuid: String,
+    interval_ms: u64,
+    window: tauri::Window,
+) -> Result<(), String> {
+    if interval_ms == 0 {
+        return Err("Device polling interval must be greater than zero".to_string());
+    }
+    let mut hardware_monitor = HardwareMonitor::current().write().await;
+    let is_already_polled = hardware_monitor.has_device_priority(&uuid);
+    hardware_monitor.set_device_priority(uuid.clone(), Duration::from_millis(interval_ms));
+    if !is_already_polled {
+        let mut readings = HardwareMonitor::poll_prioritized_device(uuid);
+        tauri::async_runtime::spawn(async move {
+            loop {
+                match readings.recv().await {
+                    Ok(payload) => {
+                        if let Err(e) = window.emit("gpu-device-parameters", payload) {
+                            error!(target: LOG_TARGET, "Error emitting gpu-device-parameters event: {}", e);
+                            break;
+                        }
+                    }
+                    Err(RecvError::Lagged(skipped)) => {
+                        warn!(target: LOG_TARGET, "Skipped {} gpu device readings", skipped);
+                    }
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+    Ok(())
+}
+
+#[tauri::command]
+async fn subscribe_hardware_status(interval_ms: u64, window: tauri::Window) -> Result<(), String> {
+    if interval_ms == 0 {
+        return Err("Hardware polling interval must be greater than zero".to_string());
+    }
+    let mut statuses = HardwareMonitor::current()
+        .write()
+        .await
+        .spawn_polling(Duration::from_millis(interval_ms));
+    tauri::async_runtime::spawn(async move {
+        loop {
+            match statuses.recv().await {
+                Ok(status) => {
+                    if let Err(e) = window.emit("hardware-status", status) {
+                        error!(target: LOG_TARGET, "Error emitting hardware-status event: {}", e);
+                        break;
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!(target: LOG_TARGET, "Skipped {} hardware statuses", skipped);
+                }
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+    Ok(())
+}
+
+#[tauri::command]
+async fn subscribe_gpu_devices(window: tauri::Window, app: tauri::AppHandle) -> Result<(), String> {
+    let config_path = app
+        .path_resolver()
+        .app_config_dir()
+        .expect("Could not get config dir");
+    let mut device_lists = HardwareMonitor::current()
+        .write()
+        .await
+        .watch_gpu_status_file(config_path)
+        .map_err(|e| e.to_string())?;
+    tauri::async_runtime::spawn(async move {
+        loop {
+            match device_lists.recv().await {
+                Ok(devices) => {
+                    if let Err(e) = window.emit("gpu-devices", devices) {
+                        error!(target: LOG_TARGET, "Error emitting gpu-devices event: {}", e);
+                        break;
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!(target: LOG_TARGET, "Skipped {} gpu device lists", skipped);
+                }
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_include_integrated_gpus(include_integrated_gpus: bool) -> Result<(), String> {
+    HardwareMonitor::current()
+        .write()
+        .await
+        .set_include_integrated_gpus(include_integrated_gpus);
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_temperature_unit(temperature_unit: TemperatureUnit) -> Result<(), String> {
+    HardwareMonitor::current()
+        .write()
+        .await
+        .set_temperature_unit(temperature_unit);
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_plausibility_thresholds(
+    thresholds: Option<PlausibilityThresholds>,
+) -> Result<(), String> {
+    HardwareMonitor::current()
+        .write()
+        .await
+        .set_plausibility_thresholds(thresholds);
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_thermal_thresholds(
+    component: ComponentKind,
+    thresholds: Option<ThermalThresholds>,
+) -> Result<(), String> {
+    HardwareMonitor::current()
+        .write()
+        .await
+        .set_thermal_thresholds(component, thresholds);
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_gpu_read_concurrency(concurrency: usize) -> Result<(), String> {
+    HardwareMonitor::current()
+        .write()
+        .await
+        .set_gpu_read_concurrency(concurrency);
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_sensor_display_names(
+    sensor_display_names: HashMap<String, String>,
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<(), String> {
+    state
+        .config
+        .write()
+        .await
+        .set_sensor_display_names(sensor_display_names.clone())
+        .await
+        .inspect_err(|e| error!(target: LOG_TARGET, "error at set_sensor_display_names {:?}", e))
+        .map_err(|e| e.to_string())?;
+    HardwareMonitor::current()
+        .write()
+        .await
+        .set_sensor_display_names(sensor_display_names);
+    Ok(())
+}
+
+#[tauri::command]
+async fn get_hardware_history(device: String) -> Result<Vec<HistorySample>, String> {
+    Ok(HardwareMonitor::current().read().await.history(&device))
+}
+
+/// Returns how many seconds of history fit the new budget
+#[tauri::command]
+async fn set_hardware_history_memory_budget(bytes: usize) -> Result<u64, String> {
+    let mut hardware_monitor = HardwareMonitor::current().write().await;
+    hardware_monitor.set_history_memory_budget(bytes);
+    Ok(hardware_monitor.history_retained_duration().as_secs())
+}
+
+#[tauri::command]
+async fn get_gpu_devices() -> Result<Vec<GpuStatus>, String> {
+    Ok(HardwareMonitor::current()
+        .read()
+        .await
+        .gpu_devices()
+        .to_vec())
+}
+
+#[tauri::command]
+async fn get_gpu_status_history() -> Result<Vec<GpuStatusTransition>, String> {
+    Ok(HardwareMonitor::current()
+        .read()
+        .await
+        .gpu_status_history()
+        .to_vec())
+}
+
+#[tauri::command]
+async fn set_nvidia_smi_fallback(enabled: bool) -> Result<(), String> {
+    HardwareMonitor::current()
+        .write()
+        .await
+        .set_nvidia_smi_fallback(enabled);
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_gpu_temperature_alert(uuid: String, threshold: Option<f32>) -> Result<(), String> {
+    let mut hardware_monitor = HardwareMonitor::current().write().await;
+    hardware_monitor.set_temperature_alert(uuid, threshold);
+    hardware_monitor
+        .save_alerts()
+        .inspect_err(|e| error!(target: LOG_TARGET, "error at set_gpu_temperature_alert {:?}", e))
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn set_rig_id(rig_id: Option<String>) -> Result<(), String> {
+    HardwareMonitor::current().write().await.set_rig_id(rig_id);
+    Ok(())
+}
+
+#[tauri::command]
+async fn merge_hardware_statuses(
+    statuses: Vec<HardwareStatus>,
+) -> Result<AggregatedStatus, String> {
+    Ok(HardwareStatus::merge(&statuses))
+}
+
+#[tauri::command]
+async fn set_hardware_failure_policy(policy: FailurePolicy) -> Result<(), String> {
+    HardwareMonitor::current()
+        .write()
+        .await
+        .set_failure_policy(policy);
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_min_gpu_usage_filter(min_usage: Option<f32>) -> Result<(), String> {
+    HardwareMonitor::current()
+        .write()
+        .await
+        .set_min_usage_filter(min_usage);
+    Ok(())
+}
+
+#[tauri::command]
+async fn start_hardware_replay(file: String, loop_replay: bool) -> Result<(), String> {
+    HardwareMonitor::current()
+        .write()
+        .await
+        .start_replay(PathBuf::from(file), loop_replay)
+        .inspect_err(|e| error!(target: LOG_TARGET, "error at start_hardware_replay {:?}", e))
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn stop_hardware_replay() -> Result<(), String> {
+    HardwareMonitor::current().write().await.stop_replay();
+    Ok(())
+}
+
+#[tauri::command]
+async fn get_hardware_status_delta(full_snapshot: bool) -> Result<HardwareStatusDelta, String> {
+    let mut hardware_monitor = HardwareMonitor::current().write().await;
+    if full_snapshot {
+        hardware_monitor.reset_status_delta();
+    }
+    Ok(hardware_monitor.status_delta())
+}
+
+#[tauri::command]
+async fn reset_peak_temperatures() -> Result<(), String> {
+    HardwareMonitor::current()
+        .write()
+        .await
+        .reset_peak_temperatures();
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_hardware_status_delta_epsilon(epsilon: f32) -> Result<(), String> {
+    HardwareMonitor::current()
+        .write()
+        .await
+        .set_status_delta_epsilon(epsilon);
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_temperature_average_window(length: usize) -> Result<(), String> {
+    HardwareMonitor::current()
+        .write()
+        .await
+        .set_temperature_average_window(length);
+    Ok(())
+}
+
+#[tauri::command]
+async fn get_last_hardware_status() -> Result<Option<HardwareStatus>, String> {
+    Ok(HardwareMonitor::current().read().await.last_status())
+}
+
+#[tauri::command]
+async fn set_thermal_limit(limit: Option<f32>) -> Result<(), String> {
+    HardwareMonitor::current()
+        .write()
+        .await
+        .set_thermal_limit(limit);
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_min_refresh_interval(interval_ms: u64) -> Result<(), String> {
+    HardwareMonitor::current()
+        .write()
+        .await
+        .set_min_refresh_interval(Duration::from_millis(interval_ms));
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_hardware_csv_logging(
+    enabled: bool,
+    max_size_bytes: Option<u64>,
+    app: tauri::AppHandle,
+) -> Result<(), String> {
+    let log_dir = app
+        .path_resolver()
+        .app_log_dir()
+        .expect("Could not get log dir");
+    let mut hardware_monitor = HardwareMonitor::current().write().await;
+    if enabled {
+        hardware_monitor.enable_csv_logging(log_dir.join("hardware.csv"), max_size_bytes);
+    } else {
+        hardware_monitor.disable_csv_logging();
+    }
+    Ok(())
+}
+
+#[tauri::command]
+async fn get_prometheus_metrics() -> Result<String, String> {
+    Ok(HardwareMonitor::current().read().await.prometheus_metrics())
+}
+
+#[tauri::command]
+async fn start_metrics_endpoint(port: u16) -> Result<(), String> {
+    prometheus_metrics::start(port)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn stop_metrics_endpoint() -> Result<bool, String> {
+    Ok(prometheus_metrics::stop().await)
+}
+
+#[tauri::command]
+async fn set_gpu_devices(devices: Vec<GpuStatus>, app: tauri::AppHandle) -> Result<(), String> {
+    let config_path = app
+        .path_resolver()
+        .app_config_dir()
+        .expect("Could not get config dir");
+    HardwareMonitor::current()
+        .write()
+        .await
+        .write_gpu_devices(config_path, devices)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn get_gpu_backend_status() -> Result<GpuBackendStatus, String> {
+    Ok(HardwareMonitor::current().read().await.gpu_backend_status())
+}
+
+#[tauri::command]
+async fn get_gpu_uptimes() -> Result<Vec<GpuUptime>, String> {
+    Ok(HardwareMonitor::current().read().await.gpu_uptimes())
+}
+
+#[tauri::command]
+async fn get_hardware_monitor_implementation() -> Result<ImplementationKind, String> {
+    Ok(HardwareMonitor::current()
+        .read()
+        .await
+        .active_implementation())
+}
+
+#[tauri::command]
+async fn clear_gpu_device_priority(uuid: String) -> Result<(), String> {
+    HardwareMonitor::current()
+        .write()
+        .await
+        .clear_device_priority(&uuid);
+    Ok(())
+}
+
 #[tauri::command]
 async fn get_seed_words(
     _window: tauri::Window,
@@ -1394,7 +1821,9 @@ async fn get_miner_metrics(
         let read = state.cached_miner_metrics.read().await;
         if let Some(metrics) = &*read {
             warn!(target: LOG_TARGET, "Already getting miner metrics, returning cached value");
-            return Ok(metrics.clone());
+            let mut metrics = metrics.clone();
+            metrics.hardware_source = ReadingSource::of_cached_sample(metrics.hardware_sample_time);
+            return Ok(metrics);
         }
         warn!(target: LOG_TARGET, "Already getting miner metrics");
         return Err("Already getting miner metrics".to_string());
@@ -1449,11 +1878,11 @@ async fn get_miner_metrics(
         .write()
         .await
         .load_status_file(config_path);
+    // Keeps the log of GPU availability changes up to date
+    let _unused = HardwareMonitor::current().write().await.read_gpu_devices();
 
-    let hardware_status = HardwareMonitor::current()
-        .write()
-        .await
-        .read_hardware_parameters();
+    let hardware_status = HardwareMonitor::read_hardware_parameters().await;
+    trace!(target: LOG_TARGET, "Hardware: {}", hardware_status);
 
     let new_systemtray_data: SystrayData = SystemtrayManager::current().create_systemtray_data(
         cpu_mining_status.hash_rate,
@@ -1474,13 +1903,17 @@ async fn get_miner_metrics(
         warn!(target: LOG_TARGET, "get_miner_metrics took too long: {:?}", timer.elapsed());
     }
 
+    let thermal_percentages = hardware_status.thermal_percentages();
+    let gpu_by_vendor = hardware_status.by_vendor();
     let ret = MinerMetrics {
         cpu: CpuMinerMetrics {
             hardware: hardware_status.cpu,
+            hardware_availability: hardware_status.cpu_availability,
             mining: cpu_mining_status,
         },
         gpu: GpuMinerMetrics {
             hardware: hardware_status.gpu,
+            hardware_availability: hardware_status.gpu_availability,
             mining: gpu_mining_status,
         },
         base_node: BaseNodeStatus {
@@ -1490,6 +1923,11 @@ async fn get_miner_metrics(
             is_connected: !connected_peers.is_empty(),
             connected_peers,
         },
+        motherboard: hardware_status.motherboard,
+        thermal_percentages,
+        gpu_by_vendor,
+        hardware_sample_time: hardware_status.sample_time,
+        hardware_source: hardware_status.source,
     };
     let mut lock = state.cached_miner_metrics.write().await;
     *lock = Some(ret.clone());
@@ -1619,12 +2057,14 @@ async fn reset_settings<'r>(
 #[derive(Debug, Serialize, Clone)]
 pub struct CpuMinerMetrics {
     hardware: Option<HardwareParameters>,
+    hardware_availability: Availability,
     mining: CpuMinerStatus,
 }
 
 #[derive(Debug, Serialize, Clone)]
 pub struct GpuMinerMetrics {
     hardware: Vec<HardwareParameters>,
+    hardware_availability: Availability,
     mining: GpuMinerStatus,
 }
 
@@ -1633,6 +2073,11 @@ pub struct MinerMetrics {
     cpu: CpuMinerMetrics,
     gpu: GpuMinerMetrics,
     base_node: BaseNodeStatus,
+    motherboard: Vec<HardwareParameters>,
+    thermal_percentages: Vec<(String, f32)>,
+    gpu_by_vendor: HashMap<GpuVendor, HardwareParameters>,
+    hardware_sample_time: SystemTime,
+    hardware_source: ReadingSource,
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -1850,6 +2295,68 @@ fn main() {
                 }
             };
 
+            let sensor_display_names = tauri::async_runtime::block_on(
+                app.state::<UniverseAppState>().config.read(),
+            )
+            .sensor_display_names()
+            .clone();
+            let mut hardware_monitor =
+                tauri::async_runtime::block_on(HardwareMonitor::current().write());
+            hardware_monitor.set_sensor_display_names(sensor_display_names);
+            let config_path = app
+                .path_resolver()
+                .app_config_dir()
+                .expect("Could not get config dir");
+            drop(
+                hardware_monitor
+                    .load_alerts(config_path)
+                    .inspect_err(|e| error!(target: LOG_TARGET, "Could not load hardware alerts: {:?}", e)),
+            );
+            let app_handle = app.handle();
+            hardware_monitor.on_temperature_alert(Box::new(move |uuid, temperature, threshold| {
+                drop(
+                    app_handle
+                        .emit_all(
+                            "hardware-temperature-alert",
+                            TemperatureAlertPayload {
+                                uuid: uuid.to_string(),
+                                temperature,
+                                threshold,
+                            },
+                        )
+                        .inspect_err(|e| error!(target: LOG_TARGET, "Could not emit event 'hardware-temperature-alert': {:?}", e)),
+                );
+            }));
+            let app_handle = app.handle();
+            hardware_monitor.on_ecc_error_detected(Box::new(move |uuid, count| {
+                drop(
+                    app_handle
+                        .emit_all(
+                            "gpu-ecc-error-detected",
+                            GpuEccErrorPayload {
+                                uuid: uuid.to_string(),
+                                count,
+                            },
+                        )
+                        .inspect_err(|e| error!(target: LOG_TARGET, "Could not emit event 'gpu-ecc-error-detected': {:?}", e)),
+                );
+            }));
+            let app_handle = app.handle();
+            hardware_monitor.on_new_peak(Box::new(move |label, max_temperature| {
+                drop(
+                    app_handle
+                        .emit_all(
+                            "hardware-new-temperature-peak",
+                            NewTemperaturePeakPayload {
+                                label: label.to_string(),
+                                max_temperature,
+                            },
+                        )
+                        .inspect_err(|e| error!(target: LOG_TARGET, "Could not emit event 'hardware-new-temperature-peak': {:?}", e)),
+                );
+            }));
+            drop(hardware_monitor);
+
             let config_path = app
                 .path_resolver()
                 .app_config_dir()
@@ -1924,6 +2431,43 @@ fn main() {
             get_paper_wallet_details,
             exit_application,
             set_excluded_gpu_devices,
+            set_gpu_device_priority,
+            subscribe_hardware_status,
+            subscribe_gpu_devices,
+            clear_gpu_device_priority,
+            reset_peak_temperatures,
+            get_hardware_monitor_implementation,
+            get_gpu_uptimes,
+            get_gpu_backend_status,
+            set_gpu_devices,
+            set_include_integrated_gpus,
+            set_min_gpu_usage_filter,
+            set_gpu_read_concurrency,
+            set_thermal_thresholds,
+            set_hardware_failure_policy,
+            set_rig_id,
+            set_gpu_temperature_alert,
+            set_nvidia_smi_fallback,
+            get_gpu_status_history,
+            get_gpu_devices,
+            get_hardware_history,
+            set_hardware_history_memory_budget,
+            set_sensor_display_names,
+            merge_hardware_statuses,
+            set_temperature_unit,
+            set_plausibility_thresholds,
+            start_hardware_replay,
+            stop_hardware_replay,
+            get_hardware_status_delta,
+            set_hardware_status_delta_epsilon,
+            set_temperature_average_window,
+            set_hardware_csv_logging,
+            set_min_refresh_interval,
+            set_thermal_limit,
+            get_last_hardware_status,
+            get_prometheus_metrics,
+            start_metrics_endpoint,
+            stop_metrics_endpoint,
             set_should_always_use_system_language,
             set_should_auto_launch,
             download_and_start_installer,
@@ -0,0 +1,71 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::LazyLock,
+};
+
+use anyhow::anyhow;
+use log::debug;
+
+const LOG_TARGET: &str = "tari::universe::nvidia_smi";
+const NVIDIA_SMI_QUERY: &str = "--query-gpu=name,uuid,temperature.gpu,utilization.gpu,power.draw";
+/// Locations outside of PATH where drivers install nvidia-smi
+const NVIDIA_SMI_FALLBACK_PATHS: [&str; 2] = ["/usr/bin/nvidia-smi", "/usr/local/bin/nvidia-smi"];
+
+/// Looked up once, nvidia-smi does not move while the app runs
+static NVIDIA_SMI_PATH: LazyLock<Option<PathBuf>> = LazyLock::new(find_nvidia_smi);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NvidiaSmiGpu {
+    pub name: String,
+    pub uuid: Option<String>,
+    pub temperature: f32,
+    pub usage_percentage: f32,
+    pub power_draw_watts: Option<f32>,
+}
+
+fn find_nvidia_smi() -> Option<PathBuf> {
+    let path = env::var_os("PATH")
+        .iter()
+        .flat_map(env::split_paths)
+        .map(|dir| dir.join("nvidia-smi"))
+        .chain(NVIDIA_SMI_FALLBACK_PATHS.iter().map(PathBuf::from))
+        .find(|path| path.is_file());
+    debug!(target: LOG_TARGET, "nvidia-smi found at {:?}", path);
+    path
+}
+
+/// Reads every GPU through the nvidia-smi CLI, for when NVML cannot be loaded
+pub fn read_gpus() -> Result<Vec<NvidiaSmiGpu>, anyhow::Error> {
+    let path: &Path = NVIDIA_SMI_PATH
+        .as_deref()
+        .ok_or_else(|| anyhow!("nvidia-smi not found"))?;
+    let output = Command::new(path)
+        .args([NVIDIA_SMI_QUERY, "--format=csv,noheader,nounits"])
+        .output()?;
+    if !output.status.success() {
+        return Err(anyhow!("nvidia-smi exited with {}", output.status));
+    }
+    Ok(parse_output(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Parses `name, uuid, temperature, utilization, power` rows, unsupported values read "[N/A]"
+pub(crate) fn parse_output(output: &str) -> Vec<NvidiaSmiGpu> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let [name, uuid, temperature, usage, power] = fields.as_slice() else {
+                return None;
+            };
+            Some(NvidiaSmiGpu {
+                name: name.to_string(),
+                uuid: uuid.starts_with("GPU-").then(|| uuid.to_string()),
+                temperature: temperature.parse().ok()?,
+                usage_percentage: usage.parse().unwrap_or_default(),
+                power_draw_watts: power.parse().ok(),
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,139 @@
+use std::{fs, path::Path};
+
+use log::debug;
+use serde::{Deserialize, Serialize};
+
+const LOG_TARGET: &str = "tari::universe::power_supply";
+const LINUX_POWER_SUPPLY_DIR: &str = "/sys/class/power_supply";
+const PMSET_PATH: &str = "/usr/bin/pmset";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PowerSource {
+    Ac,
+    Battery,
+}
+
+/// Power source of a machine with a battery, desktops have none to report
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PowerStatus {
+    pub power_source: PowerSource,
+    pub battery_percentage: Option<f32>,
+}
+
+/// Reads whether the machine runs on AC or battery, `None` when it has no battery
+pub fn read_power_status() -> Option<PowerStatus> {
+    if cfg!(target_os = "linux") {
+        read_sysfs_power_status(Path::new(LINUX_POWER_SUPPLY_DIR))
+    } else if cfg!(target_os = "macos") {
+        read_pmset_power_status()
+    } else if cfg!(target_os = "windows") {
+        read_windows_power_status()
+    } else {
+        None
+    }
+}
+
+/// Reads the `type`, `online` and `capacity` attributes of every power supply the kernel lists
+pub(crate) fn read_sysfs_power_status(power_supply_dir: &Path) -> Option<PowerStatus> {
+    let supplies = fs::read_dir(power_supply_dir)
+        .map_err(|e| debug!(target: LOG_TARGET, "No power supplies listed: {}", e))
+        .ok()?;
+
+    let mut battery_capacities = vec![];
+    let mut ac_online = false;
+    for supply in supplies.filter_map(Result::ok).map(|entry| entry.path()) {
+        let read = |attribute: &str| {
+            fs::read_to_string(supply.join(attribute))
+                .ok()
+                .map(|value| value.trim().to_string())
+        };
+        match read("type").as_deref() {
+            Some("Battery") => {
+                // Peripherals such as wireless mice report batteries of their own
+                if read("scope").as_deref() == Some("Device") {
+                    continue;
+                }
+                battery_capacities.push(read("capacity").and_then(|c| c.parse::<f32>().ok()));
+            }
+            Some("Mains" | "USB") => ac_online |= read("online").as_deref() == Some("1"),
+            _ => {}
+        }
+    }
+
+    if battery_capacities.is_empty() {
+        return None;
+    }
+    let capacities: Vec<f32> = battery_capacities.into_iter().flatten().collect();
+    Some(PowerStatus {
+        power_source: if ac_online {
+            PowerSource::Ac
+        } else {
+            PowerSource::Battery
+        },
+        battery_percentage: (!capacities.is_empty())
+            .then(|| capacities.iter().sum::<f32>() / capacities.len() as f32),
+    })
+}
+
+/// `pmset` reports the IOKit power sources, e.g. "Now drawing from 'Battery Power'"
+fn read_pmset_power_status() -> Option<PowerStatus> {
+    let output = std::process::Command::new(PMSET_PATH)
+        .args(["-g", "batt"])
+        .output()
+        .map_err(|e| debug!(target: LOG_TARGET, "Failed to run pmset: {}", e))
+        .ok()?;
+    parse_pmset_output(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parses `pmset -g batt`, a desktop lists the AC source but no `InternalBattery` line
+pub(crate) fn parse_pmset_output(output: &str) -> Option<PowerStatus> {
+    let battery_line = output
+        .lines()
+        .find(|line| line.contains("InternalBattery"))?;
+    let battery_percentage = battery_line
+        .split_whitespace()
+        .find_map(|field| field.strip_suffix("%;"))
+        .and_then(|percentage| percentage.parse().ok());
+    Some(PowerStatus {
+        power_source: if output.contains("'AC Power'") {
+            PowerSource::Ac
+        } else {
+            PowerSource::Battery
+        },
+        battery_percentage,
+    })
+}
+
+#[cfg(target_os = "windows")]
+fn read_windows_power_status() -> Option<PowerStatus> {
+    use windows_sys::Win32::System::Power::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
+
+    /// Flag of `BatteryFlag` set on machines without a battery
+    const NO_SYSTEM_BATTERY: u8 = 128;
+    const UNKNOWN: u8 = 255;
+
+    // SAFETY: SYSTEM_POWER_STATUS is plain data, all zeroes is a valid value that the call overwrites
+    let mut status: SYSTEM_POWER_STATUS = unsafe { std::mem::zeroed() };
+    // SAFETY: the pointer is to a live, writable SYSTEM_POWER_STATUS
+    if unsafe { GetSystemPowerStatus(&mut status) } == 0 {
+        debug!(target: LOG_TARGET, "GetSystemPowerStatus failed");
+        return None;
+    }
+    if status.BatteryFlag == UNKNOWN || status.BatteryFlag & NO_SYSTEM_BATTERY != 0 {
+        return None;
+    }
+    Some(PowerStatus {
+        power_source: if status.ACLineStatus == 1 {
+            PowerSource::Ac
+        } else {
+            PowerSource::Battery
+        },
+        battery_percentage: (status.BatteryLifePercent != UNKNOWN)
+            .then(|| f32::from(status.BatteryLifePercent)),
+    })
+}
+
+#[cfg(not(target_os = "windows"))]
+fn read_windows_power_status() -> Option<PowerStatus> {
+    None
+}
@@ -0,0 +1,243 @@
+use std::{
+    fmt::Write as _,
+    net::{Ipv4Addr, SocketAddr},
+    sync::LazyLock,
+    time::Duration,
+};
+
+use anyhow::anyhow;
+use log::{error, info, warn};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    sync::Mutex,
+    task::JoinHandle,
+};
+
+use crate::hardware_monitor::{HardwareMonitor, HardwareParameters};
+
+const LOG_TARGET: &str = "tari::universe::prometheus_metrics";
+
+/// Largest request head accepted, a scrape sends a request line and a few short headers
+const MAX_REQUEST_HEAD_BYTES: usize = 8 * 1024;
+
+/// A client that does not finish its request head in time is disconnected
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+const METRICS_PATH: &str = "/metrics";
+
+/// The running metrics endpoint, there is at most one
+static ENDPOINT: LazyLock<Mutex<Option<MetricsEndpoint>>> = LazyLock::new(|| Mutex::new(None));
+
+struct MetricsEndpoint {
+    address: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+struct Metric {
+    name: &'static str,
+    help: &'static str,
+    value: fn(&HardwareParameters) -> Option<f32>,
+}
+
+const CPU_METRICS: [Metric; 2] = [
+    Metric {
+        name: "universe_cpu_usage_percent",
+        help: "CPU usage in percent",
+        value: |cpu| Some(cpu.usage_percentage),
+    },
+    Metric {
+        name: "universe_cpu_temperature_celsius",
+        help: "CPU temperature in Celsius",
+        value: |cpu| Some(cpu.current_temperature),
+    },
+];
+
+const GPU_METRICS: [Metric; 5] = [
+    Metric {
+        name: "universe_gpu_usage_percent",
+        help: "GPU usage in percent",
+        value: |gpu| Some(gpu.usage_percentage),
+    },
+    Metric {
+        name: "universe_gpu_temperature_celsius",
+        help: "GPU temperature in Celsius",
+        value: |gpu| Some(gpu.current_temperature),
+    },
+    Metric {
+        name: "universe_gpu_memory_temperature_celsius",
+        help: "GPU memory temperature in Celsius",
+        value: |gpu| gpu.memory_temperature,
+    },
+    Metric {
+        name: "universe_gpu_power_watts",
+        help: "GPU power draw in watts",
+        value: |gpu| gpu.power_draw_watts,
+    },
+    Metric {
+        name: "universe_gpu_fan_speed_percent",
+        help: "GPU fan speed in percent",
+        value: |gpu| gpu.fan_speed_percentage.map(|speed| speed as f32),
+    },
+];
+
+/// Renders the Celsius readings in the Prometheus text exposition format,
+/// a metric is left out entirely when no device reports it
+pub fn render(cpu: Option<&HardwareParameters>, gpu: &[HardwareParameters]) -> String {
+    let mut output = String::new();
+    render_metrics(&mut output, &CPU_METRICS, cpu.into_iter());
+    render_metrics(&mut output, &GPU_METRICS, gpu.iter());
+    output
+}
+
+fn render_metrics<'a>(
+    output: &mut String,
+    metrics: &[Metric],
+    devices: impl Iterator<Item = &'a HardwareParameters> + Clone,
+) {
+    for metric in metrics {
+        let samples: Vec<(&HardwareParameters, f32)> = devices
+            .clone()
+            .filter_map(|device| (metric.value)(device).map(|value| (device, value)))
+            .filter(|(_, value)| value.is_finite())
+            .collect();
+        if samples.is_empty() {
+            continue;
+        }
+        // Writing to a String cannot fail
+        let _ = writeln!(output, "# HELP {} {}", metric.name, metric.help);
+        let _ = writeln!(output, "# TYPE {} gauge", metric.name);
+        for (device, value) in samples {
+            let _ = write!(
+                output,
+                "{}{{device=\"{}\"",
+                metric.name,
+                escape_label(&device.label)
+            );
+            if let Some(uuid) = &device.uuid {
+                let _ = write!(output, ",uuid=\"{}\"", escape_label(uuid));
+            }
+            let _ = writeln!(output, "}} {}", value);
+        }
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Serves the hardware metrics on `port` of the loopback interface, only one endpoint can run
+pub async fn start(port: u16) -> Result<(), anyhow::Error> {
+    let mut endpoint = ENDPOINT.lock().await;
+    if let Some(running) = endpoint
+        .as_ref()
+        .filter(|running| !running.task.is_finished())
+    {
+        return Err(anyhow!(
+            "Hardware metrics are already served on {}",
+            running.address
+        ));
+    }
+    let address = SocketAddr::from((Ipv4Addr::LOCALHOST, port));
+    let listener = TcpListener::bind(address).await?;
+    info!(target: LOG_TARGET, "Serving hardware metrics on {}", address);
+    let task = tokio::spawn(async move {
+        if let Err(e) = serve(listener).await {
+            error!(target: LOG_TARGET, "Hardware metrics endpoint stopped: {}", e);
+        }
+    });
+    *endpoint = Some(MetricsEndpoint { address, task });
+    Ok(())
+}
+
+/// Stops the metrics endpoint, `false` if it was not running
+pub async fn stop() -> bool {
+    match ENDPOINT.lock().await.take() {
+        Some(endpoint) => {
+            endpoint.task.abort();
+            info!(target: LOG_TARGET, "Stopped serving hardware metrics on {}", endpoint.address);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Answers `GET /metrics` with the cached hardware metrics,
+/// so a scrape never triggers a sensor read of its own
+async fn serve(listener: TcpListener) -> Result<(), anyhow::Error> {
+    loop {
+        let (mut stream, peer) = listener.accept().await?;
+        tokio::spawn(async move {
+            let head = match tokio::time::timeout(REQUEST_TIMEOUT, read_request_head(&mut stream))
+                .await
+            {
+                Ok(Ok(head)) => head,
+                Ok(Err(e)) => {
+                    warn!(target: LOG_TARGET, "Failed to read metrics request from {}: {}", peer, e);
+                    return;
+                }
+                Err(_) => {
+                    warn!(target: LOG_TARGET, "Metrics request from {} timed out", peer);
+                    return;
+                }
+            };
+            let response = match request_target(&String::from_utf8_lossy(&head)) {
+                Some(("GET", METRICS_PATH)) => {
+                    let body = HardwareMonitor::current().read().await.prometheus_metrics();
+                    http_response("200 OK", &body)
+                }
+                Some((_, METRICS_PATH)) => http_response("405 Method Not Allowed", ""),
+                Some(_) => http_response("404 Not Found", ""),
+                None => http_response("400 Bad Request", ""),
+            };
+            if let Err(e) = stream.write_all(response.as_bytes()).await {
+                warn!(target: LOG_TARGET, "Failed to send metrics to {}: {}", peer, e);
+            }
+        });
+    }
+}
+
+/// Reads up to the blank line ending the request head, which may arrive over several reads
+async fn read_request_head(stream: &mut TcpStream) -> Result<Vec<u8>, anyhow::Error> {
+    let mut head = Vec::new();
+    let mut buffer = [0u8; 1024];
+    while !head.windows(4).any(|window| window == b"\r\n\r\n") {
+        if head.len() > MAX_REQUEST_HEAD_BYTES {
+            return Err(anyhow!(
+                "Request head is larger than {} bytes",
+                MAX_REQUEST_HEAD_BYTES
+            ));
+        }
+        let read = stream.read(&mut buffer).await?;
+        if read == 0 {
+            return Err(anyhow!("Connection closed before the request was complete"));
+        }
+        head.extend(buffer.iter().take(read));
+    }
+    Ok(head)
+}
+
+/// Method and path of the request line, without the query string.
+/// `None` if the request line is malformed.
+pub(crate) fn request_target(head: &str) -> Option<(&str, &str)> {
+    let mut request_line = head.lines().next()?.split_whitespace();
+    let method = request_line.next()?;
+    let target = request_line.next()?;
+    if !request_line.next()?.starts_with("HTTP/") {
+        return None;
+    }
+    let path = target.split('?').next().unwrap_or(target);
+    Some((method, path))
+}
+
+fn http_response(status: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )
+}
@@ -345,10 +345,7 @@ async fn get_telemetry_data(
         }
     };
 
-    let hardware_status = HardwareMonitor::current()
-        .write()
-        .await
-        .read_hardware_parameters();
+    let hardware_status = HardwareMonitor::read_hardware_parameters().await;
 
     let p2pool_stats = p2pool_manager.get_stats().await.inspect_err(|e| {
         warn!(target: LOG_TARGET, "Error getting p2pool stats: {:?}", e);
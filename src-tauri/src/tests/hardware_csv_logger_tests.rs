@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use crate::hardware_csv_logger::{csv_header, csv_row, parse_csv_log, HardwareCsvLogger};
+    use crate::hardware_monitor::{Availability, HardwareParameters};
+
+    fn device(label: &str, temperature: f32, usage: f32) -> HardwareParameters {
+        HardwareParameters {
+            label: label.to_string(),
+            current_temperature: temperature,
+            usage_percentage: usage,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_csv_row_has_a_column_group_per_gpu() {
+        let cpu = device("AMD Ryzen 9 5950X", 61.3, 87.5);
+        let gpu = HardwareParameters {
+            power_draw_watts: Some(312.4),
+            ..device("NVIDIA GeForce RTX 3080", 65.0, 98.0)
+        };
+        let sample_time = UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+
+        assert_eq!(
+            csv_header(&[gpu.label.clone()]),
+            "timestamp_ms,cpu_temperature,cpu_usage,NVIDIA GeForce RTX 3080 temperature,\
+            NVIDIA GeForce RTX 3080 usage,NVIDIA GeForce RTX 3080 power_watts\n"
+        );
+        assert_eq!(
+            csv_row(sample_time, Some(&cpu), &[gpu]),
+            "1700000000123,61.3,87.5,65.0,98.0,312.4\n"
+        );
+        // A failed CPU read and an unknown power draw leave their cells empty
+        assert_eq!(
+            csv_row(sample_time, None, &[device("Arc A750", 50.0, 10.0)]),
+            "1700000000123,,,50.0,10.0,\n"
+        );
+    }
+
+    #[test]
+    fn test_csv_log_is_rotated_when_it_exceeds_its_size() {
+        let file = std::env::temp_dir().join(format!("hardware_{}.csv", std::process::id()));
+        let rotated = file.with_extension("csv.1");
+        let cpu = device("CPU", 60.0, 50.0);
+        let mut logger = HardwareCsvLogger::new(file.clone());
+        logger.set_max_size(70);
+
+        for _ in 0..3 {
+            logger
+                .log(UNIX_EPOCH, Some(&cpu), &[])
+                .expect("row should be written");
+        }
+        let current = std::fs::read_to_string(&file).unwrap_or_default();
+        let previous = std::fs::read_to_string(&rotated).unwrap_or_default();
+        drop(std::fs::remove_file(&file));
+        drop(std::fs::remove_file(&rotated));
+
+        // The header and two 12 byte rows fit, the third row starts a new file
+        assert_eq!(previous.lines().count(), 3);
+        assert_eq!(
+            current.lines().collect::<Vec<_>>(),
+            vec!["timestamp_ms,cpu_temperature,cpu_usage", "0,60.0,50.0"]
+        );
+    }
+
+    #[test]
+    fn test_leftover_csv_log_with_other_columns_is_rotated() {
+        let file =
+            std::env::temp_dir().join(format!("hardware_leftover_{}.csv", std::process::id()));
+        let rotated = file.with_extension("csv.1");
+        let leftover = csv_header(&["Arc A750".to_string()]) + "0,60.0,50.0,50.0,10.0,\n";
+        std::fs::write(&file, &leftover).expect("leftover log should be written");
+
+        HardwareCsvLogger::new(file.clone())
+            .log(UNIX_EPOCH, Some(&device("CPU", 60.0, 50.0)), &[])
+            .expect("row should be written");
+        let current = std::fs::read_to_string(&file).unwrap_or_default();
+        let previous = std::fs::read_to_string(&rotated).unwrap_or_default();
+        drop(std::fs::remove_file(&file));
+        drop(std::fs::remove_file(&rotated));
+
+        assert_eq!(previous, leftover);
+        assert_eq!(
+            current.lines().collect::<Vec<_>>(),
+            vec!["timestamp_ms,cpu_temperature,cpu_usage", "0,60.0,50.0"]
+        );
+    }
+
+    #[test]
+    fn test_csv_log_is_read_back_for_replay() {
+        let gpu = |temperature: f32| HardwareParameters {
+            power_draw_watts: Some(300.0),
+            ..device("NVIDIA GeForce RTX 3080", temperature, 98.0)
+        };
+        let log = csv_header(&["NVIDIA GeForce RTX 3080".to_string()])
+            + &csv_row(
+                UNIX_EPOCH + Duration::from_millis(1_000),
+                Some(&device("CPU", 60.0, 50.0)),
+                &[gpu(70.0)],
+            )
+            + &csv_row(
+                UNIX_EPOCH + Duration::from_millis(2_000),
+                None,
+                &[gpu(65.0)],
+            );
+
+        let statuses = parse_csv_log(&log).expect("log should parse");
+        assert_eq!(statuses.len(), 2);
+        let cpu = statuses[0].cpu.as_ref().expect("CPU of the first row");
+        assert_eq!(
+            (cpu.current_temperature, cpu.usage_percentage),
+            (60.0, 50.0)
+        );
+        assert_eq!(statuses[1].cpu_availability, Availability::Unavailable);
+        assert_eq!(
+            statuses[1].sample_time,
+            UNIX_EPOCH + Duration::from_millis(2_000)
+        );
+
+        let replayed = &statuses[1].gpu[0];
+        assert_eq!(replayed.label, "NVIDIA GeForce RTX 3080");
+        assert_eq!(replayed.power_draw_watts, Some(300.0));
+        // The extremes carry over from the previous row
+        assert_eq!(
+            (replayed.max_temperature, replayed.min_temperature),
+            (70.0, 65.0)
+        );
+
+        assert!(parse_csv_log("time,temperature\n").is_err());
+        assert!(parse_csv_log(&(log + "3000,61.0\n")).is_err());
+    }
+}
@@ -0,0 +1,921 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{HashMap, VecDeque},
+        path::PathBuf,
+        sync::Mutex,
+        time::{Duration, Instant, SystemTime},
+    };
+
+    use tokio::sync::RwLock;
+
+    use crate::hardware_monitor::{
+        average_cpu_temperature, ccd_temperature_summary, cpu_label, cpu_usage_diff_interval,
+        dedupe_aliased_sensors, find_previous_gpu_parameters, parse_cpu_list,
+        projected_temperature, read_gpu_status_file, rolling_average, session_extremes,
+        session_max_power, Availability, FailurePolicy, GpuStatus, GpuVendor, HardwareMonitor,
+        HardwareMonitorError, HardwareMonitorImpl, HardwareParameters, HardwareStatus,
+        HistorySample, ImplementationKind, MemoryParameters, ReadingSource, TemperatureUnit,
+        ThermalLevel, ThermalThresholds,
+    };
+
+    /// Backend with scripted readings, each poll takes the next CPU and GPU temperatures
+    struct MockHardwareMonitor {
+        cpu_temperatures: Mutex<VecDeque<f32>>,
+        gpu_temperatures: Mutex<VecDeque<Vec<f32>>>,
+        gpu_library_loaded: bool,
+    }
+
+    impl MockHardwareMonitor {
+        fn new(cpu_temperatures: Vec<f32>, gpu_temperatures: Vec<Vec<f32>>) -> Self {
+            MockHardwareMonitor {
+                cpu_temperatures: Mutex::new(cpu_temperatures.into()),
+                gpu_temperatures: Mutex::new(gpu_temperatures.into()),
+                gpu_library_loaded: true,
+            }
+        }
+    }
+
+    impl HardwareMonitorImpl for MockHardwareMonitor {
+        fn get_implementation_kind(&self) -> ImplementationKind {
+            ImplementationKind::Fallback
+        }
+        fn read_cpu_parameters(
+            &self,
+            current_parameters: Option<HardwareParameters>,
+        ) -> Result<HardwareParameters, HardwareMonitorError> {
+            let temperature = self
+                .cpu_temperatures
+                .lock()
+                .expect("mock lock")
+                .pop_front()
+                .unwrap_or_default();
+            let (max_temperature, min_temperature) =
+                session_extremes(current_parameters.as_ref(), temperature);
+            Ok(HardwareParameters {
+                label: "Mock CPU".to_string(),
+                current_temperature: temperature,
+                max_temperature,
+                min_temperature,
+                ..Default::default()
+            })
+        }
+        fn read_gpu_parameters(
+            &self,
+            current_parameters: Vec<HardwareParameters>,
+        ) -> Result<Vec<HardwareParameters>, HardwareMonitorError> {
+            let temperatures = self
+                .gpu_temperatures
+                .lock()
+                .expect("mock lock")
+                .pop_front()
+                .unwrap_or_default();
+            Ok(temperatures
+                .into_iter()
+                .enumerate()
+                .map(|(i, temperature)| {
+                    let uuid = format!("GPU-{}", i);
+                    let previous =
+                        find_previous_gpu_parameters(&current_parameters, Some(&uuid), i);
+                    let (max_temperature, min_temperature) =
+                        session_extremes(previous, temperature);
+                    HardwareParameters {
+                        label: uuid.clone(),
+                        uuid: Some(uuid),
+                        current_temperature: temperature,
+                        max_temperature,
+                        min_temperature,
+                        ..Default::default()
+                    }
+                })
+                .collect())
+        }
+        fn read_gpu_device_parameters(
+            &self,
+            _uuid: &str,
+            _current_parameters: Option<HardwareParameters>,
+        ) -> Option<HardwareParameters> {
+            None
+        }
+        fn read_motherboard_parameters(
+            &self,
+            _current_parameters: Vec<HardwareParameters>,
+        ) -> Vec<HardwareParameters> {
+            vec![]
+        }
+        fn read_memory_parameters(&self) -> Option<MemoryParameters> {
+            None
+        }
+        fn read_gpu_devices(&self) -> Vec<GpuStatus> {
+            vec![]
+        }
+        fn has_gpu_sensors(&self) -> bool {
+            self.gpu_library_loaded
+        }
+        fn set_gpu_read_concurrency(&self, _concurrency: usize) {}
+        fn gpu_read_concurrency(&self) -> Option<usize> {
+            None
+        }
+        fn set_failure_policy(&self, _policy: FailurePolicy) {}
+        fn load_status_file(&self, _config_path: PathBuf) -> Result<(), anyhow::Error> {
+            Ok(())
+        }
+        fn _log_all_components(&self) {}
+    }
+
+    fn mock_monitor(cpu: Vec<f32>, gpu: Vec<Vec<f32>>) -> HardwareMonitor {
+        HardwareMonitor::with_implementation(Box::new(MockHardwareMonitor::new(cpu, gpu)))
+    }
+
+    #[test]
+    fn test_first_read_initializes_the_session_extremes() {
+        let mut monitor = mock_monitor(vec![55.0], vec![vec![65.0]]);
+
+        let status = monitor.read_hardware_parameters_blocking();
+        let cpu = status.cpu.expect("CPU reading");
+        assert_eq!((cpu.max_temperature, cpu.min_temperature), (55.0, 55.0));
+        assert_eq!(status.gpu[0].max_temperature, 65.0);
+        assert_eq!(status.gpu[0].min_temperature, 65.0);
+    }
+
+    #[test]
+    fn test_max_temperature_never_decreases_across_polls() {
+        let mut monitor = mock_monitor(
+            vec![60.0, 80.0, 70.0],
+            vec![vec![50.0, 40.0], vec![75.0, 45.0], vec![65.0, 42.0]],
+        );
+
+        let maxima: Vec<(f32, f32, f32)> = (0..3)
+            .map(|_| {
+                let status = monitor.read_hardware_parameters_blocking();
+                (
+                    status
+                        .cpu
+                        .map(|cpu| cpu.max_temperature)
+                        .unwrap_or_default(),
+                    status.gpu[0].max_temperature,
+                    status.gpu[1].max_temperature,
+                )
+            })
+            .collect();
+        assert_eq!(
+            maxima,
+            vec![(60.0, 50.0, 40.0), (80.0, 75.0, 45.0), (80.0, 75.0, 45.0)]
+        );
+    }
+
+    #[test]
+    fn test_status_delta_shows_the_same_devices_as_the_full_status() {
+        let mut monitor = mock_monitor(vec![55.0], vec![vec![65.0]]);
+        monitor.set_min_usage_filter(Some(10.0));
+        monitor.set_sensor_display_names(HashMap::from([(
+            "Mock CPU".to_string(),
+            "Processor".to_string(),
+        )]));
+
+        let status = monitor.read_hardware_parameters_blocking();
+        let delta = monitor.status_delta();
+        assert!(status.gpu.is_empty());
+        assert!(delta.gpu.is_empty());
+        assert_eq!(delta.gpu_count, 0);
+        assert_eq!(
+            delta.cpu.map(|cpu| cpu.label),
+            status.cpu.map(|cpu| cpu.label)
+        );
+    }
+
+    #[test]
+    fn test_empty_gpu_list_is_reported_as_no_gpus() {
+        let mut monitor = mock_monitor(vec![55.0], vec![vec![]]);
+
+        let status = monitor.read_hardware_parameters_blocking();
+        assert!(status.gpu.is_empty());
+        assert!(status.cpu.is_some());
+        assert_eq!(status.gpu_availability, Availability::Available);
+    }
+
+    #[test]
+    fn test_gpus_read_without_the_gpu_library_are_reported_available() {
+        let mut backend = MockHardwareMonitor::new(vec![55.0, 55.0], vec![vec![65.0], vec![]]);
+        backend.gpu_library_loaded = false;
+        let mut monitor = HardwareMonitor::with_implementation(Box::new(backend));
+
+        let status = monitor.read_hardware_parameters_blocking();
+        assert_eq!(status.gpu_availability, Availability::Available);
+
+        let status = monitor.read_hardware_parameters_blocking();
+        assert_eq!(status.gpu_availability, Availability::Unavailable);
+    }
+
+    #[test]
+    fn test_last_status_returns_the_previous_read_as_cached() {
+        let mut monitor = mock_monitor(vec![55.0], vec![vec![65.0]]);
+        assert!(monitor.last_status().is_none());
+
+        let status = monitor.read_hardware_parameters_blocking();
+        let last = monitor.last_status().expect("status of the previous read");
+        assert_eq!(last.source, ReadingSource::Cached);
+        assert_eq!(last.sample_time, status.sample_time);
+        assert_eq!(last.cpu.map(|cpu| cpu.current_temperature), Some(55.0));
+    }
+
+    #[tokio::test]
+    async fn test_injected_monitor_is_read_instead_of_the_global_one() {
+        let mut hardware_monitor = mock_monitor(vec![55.0, 60.0], vec![vec![65.0], vec![70.0]]);
+        hardware_monitor.set_min_refresh_interval(Duration::from_secs(1));
+        let monitor = RwLock::new(hardware_monitor);
+
+        let status = HardwareMonitor::read_hardware_parameters_from(&monitor).await;
+        assert_eq!(status.source, ReadingSource::Fresh);
+        assert_eq!(status.cpu.map(|cpu| cpu.current_temperature), Some(55.0));
+        assert_eq!(status.gpu[0].current_temperature, 65.0);
+
+        // Within the refresh interval the scripted second reading is not taken
+        let cached = HardwareMonitor::read_hardware_parameters_from(&monitor).await;
+        assert_eq!(cached.source, ReadingSource::Cached);
+        assert_eq!(cached.gpu[0].current_temperature, 65.0);
+
+        monitor
+            .write()
+            .await
+            .set_min_refresh_interval(Duration::ZERO);
+        let fresh = HardwareMonitor::read_hardware_parameters_from(&monitor).await;
+        assert_eq!(fresh.gpu[0].current_temperature, 70.0);
+    }
+
+    #[test]
+    fn test_reads_within_min_refresh_interval_return_the_cached_status() {
+        let mut monitor = mock_monitor(vec![55.0], vec![vec![65.0]]);
+        monitor.set_min_refresh_interval(Duration::from_secs(1));
+        let status = monitor.read_hardware_parameters_blocking();
+        assert_eq!(status.source, ReadingSource::Fresh);
+
+        let cached = monitor
+            .recent_status(Instant::now())
+            .expect("status within the refresh interval");
+        assert_eq!(cached.source, ReadingSource::Cached);
+        assert_eq!(cached.sample_time, status.sample_time);
+        assert_eq!(cached.gpu[0].current_temperature, 65.0);
+
+        assert!(monitor
+            .recent_status(Instant::now() + Duration::from_secs(2))
+            .is_none());
+        monitor.set_min_refresh_interval(Duration::ZERO);
+        assert!(monitor.recent_status(Instant::now()).is_none());
+    }
+
+    fn gpu(uuid: &str, max_temperature: f32) -> HardwareParameters {
+        HardwareParameters {
+            label: uuid.to_string(),
+            uuid: Some(uuid.to_string()),
+            max_temperature,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_gpu_maxima_follow_uuid_when_device_count_drops() {
+        let previous = vec![gpu("GPU-a", 80.0), gpu("GPU-b", 70.0), gpu("GPU-c", 60.0)];
+
+        // GPU-b disappeared, so GPU-c is now enumerated at index 1
+        let surviving = find_previous_gpu_parameters(&previous, Some("GPU-c"), 1);
+        assert_eq!(surviving.map(|p| p.max_temperature), Some(60.0));
+
+        let first = find_previous_gpu_parameters(&previous, Some("GPU-a"), 0);
+        assert_eq!(first.map(|p| p.max_temperature), Some(80.0));
+
+        let vanished = find_previous_gpu_parameters(&previous[..1], Some("GPU-b"), 1);
+        assert!(vanished.is_none());
+    }
+
+    #[test]
+    fn test_gpu_maxima_fall_back_to_index_without_uuid() {
+        let previous = vec![HardwareParameters {
+            max_temperature: 75.0,
+            ..Default::default()
+        }];
+
+        let by_index = find_previous_gpu_parameters(&previous, None, 0);
+        assert_eq!(by_index.map(|p| p.max_temperature), Some(75.0));
+        assert!(find_previous_gpu_parameters(&previous, None, 1).is_none());
+    }
+
+    #[test]
+    fn test_kelvin_conversion_round_trips() {
+        let unit = TemperatureUnit::Kelvin;
+
+        assert_eq!(TemperatureUnit::default(), TemperatureUnit::Celsius);
+        assert_eq!(unit.from_celsius(0.0), 273.15);
+        assert!((unit.to_celsius(unit.from_celsius(65.5)) - 65.5).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_fahrenheit_conversion_round_trips() {
+        let unit = TemperatureUnit::Fahrenheit;
+
+        assert_eq!(unit.from_celsius(0.0), 32.0);
+        assert_eq!(unit.from_celsius(100.0), 212.0);
+        assert!((unit.to_celsius(unit.from_celsius(65.5)) - 65.5).abs() < 0.001);
+
+        let unread = HardwareParameters::default().with_temperature_unit(unit);
+        assert!(unread.min_temperature.is_finite());
+    }
+
+    #[test]
+    fn test_parameters_are_converted_at_the_output() {
+        let parameters = HardwareParameters {
+            current_temperature: 50.0,
+            max_temperature: 70.0,
+            slowdown_temperature: Some(90.0),
+            ..Default::default()
+        };
+
+        let kelvin = parameters
+            .clone()
+            .with_temperature_unit(TemperatureUnit::Kelvin);
+        assert!((kelvin.current_temperature - 323.15).abs() < 0.001);
+        assert!((kelvin.max_temperature - 343.15).abs() < 0.001);
+        assert_eq!(kelvin.slowdown_temperature.map(|t| t.round()), Some(363.0));
+
+        let celsius = parameters.with_temperature_unit(TemperatureUnit::Celsius);
+        assert_eq!(celsius.current_temperature, 50.0);
+    }
+
+    #[test]
+    fn test_cpu_usage_diff_interval_is_sane() {
+        let interval = cpu_usage_diff_interval();
+
+        assert!(interval >= Duration::from_millis(100));
+        assert!(interval >= sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+        assert!(interval <= Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_cpu_temperature_without_matching_components_is_finite() {
+        let temperature = average_cpu_temperature(&[]);
+
+        assert!(temperature.is_finite());
+        assert_eq!(temperature, 0.0);
+        assert_eq!(average_cpu_temperature(&[40.0, 50.0]), 45.0);
+    }
+
+    #[test]
+    fn test_cpu_label_without_reported_cpus() {
+        assert_eq!(cpu_label(&[]), "Unknown CPU");
+    }
+
+    #[test]
+    fn test_hybrid_cpu_lists_are_parsed() {
+        assert_eq!(parse_cpu_list("0-3\n"), Some(vec![0, 1, 2, 3]));
+        assert_eq!(parse_cpu_list("16-17,20"), Some(vec![16, 17, 20]));
+        assert_eq!(parse_cpu_list(""), Some(vec![]));
+        assert_eq!(parse_cpu_list("0-x"), None);
+    }
+
+    #[test]
+    fn test_reset_peaks_restart_from_the_next_sample() {
+        let mut parameters = HardwareParameters {
+            current_temperature: 60.0,
+            max_temperature: 95.0,
+            min_temperature: 40.0,
+            ..Default::default()
+        };
+        assert_eq!(session_extremes(Some(&parameters), 62.0), (95.0, 40.0));
+
+        parameters.reset_peak_temperatures();
+
+        assert_eq!(session_extremes(Some(&parameters), 62.0), (62.0, 62.0));
+    }
+
+    #[test]
+    fn test_monitor_reset_restarts_the_session_extremes() {
+        let mut monitor = mock_monitor(vec![90.0, 60.0], vec![vec![85.0], vec![65.0]]);
+        let status = monitor.read_hardware_parameters_blocking();
+        assert_eq!(status.gpu[0].max_temperature, 85.0);
+
+        monitor.reset_peak_temperatures();
+
+        let status = monitor.read_hardware_parameters_blocking();
+        let cpu = status.cpu.expect("CPU reading");
+        assert_eq!((cpu.max_temperature, cpu.min_temperature), (60.0, 60.0));
+        assert_eq!(status.gpu[0].max_temperature, 65.0);
+        assert_eq!(status.gpu[0].min_temperature, 65.0);
+    }
+
+    #[test]
+    fn test_rolling_average_keeps_the_last_readings() {
+        let mut window = VecDeque::new();
+
+        // Fewer readings than the window averages what is there
+        assert_eq!(rolling_average(&mut window, 3, 60.0), 60.0);
+        assert_eq!(rolling_average(&mut window, 3, 70.0), 65.0);
+        assert_eq!(rolling_average(&mut window, 3, 80.0), 70.0);
+        assert_eq!(rolling_average(&mut window, 3, 90.0), 80.0);
+        assert_eq!(window.len(), 3);
+
+        // Shrinking the window drops the oldest readings
+        assert_eq!(rolling_average(&mut window, 1, 50.0), 50.0);
+    }
+
+    #[test]
+    fn test_peak_power_is_carried_until_reset() {
+        let mut parameters = HardwareParameters {
+            max_power_watts: session_max_power(None, Some(310.0)),
+            ..Default::default()
+        };
+        assert_eq!(
+            session_max_power(Some(&parameters), Some(250.0)),
+            Some(310.0)
+        );
+        assert_eq!(session_max_power(Some(&parameters), None), Some(310.0));
+        assert_eq!(
+            session_max_power(Some(&parameters), Some(340.0)),
+            Some(340.0)
+        );
+
+        parameters.reset_peak_temperatures();
+
+        assert_eq!(
+            session_max_power(Some(&parameters), Some(250.0)),
+            Some(250.0)
+        );
+    }
+
+    #[test]
+    fn test_thermal_levels_use_hysteresis() {
+        let thresholds = ThermalThresholds {
+            warning_threshold: 80.0,
+            critical_threshold: 90.0,
+        };
+
+        assert_eq!(
+            thresholds.level(ThermalLevel::Normal, 79.0),
+            ThermalLevel::Normal
+        );
+        assert_eq!(
+            thresholds.level(ThermalLevel::Normal, 80.0),
+            ThermalLevel::Warning
+        );
+        // Hovering just below the threshold keeps the level instead of re-alerting
+        assert_eq!(
+            thresholds.level(ThermalLevel::Warning, 79.0),
+            ThermalLevel::Warning
+        );
+        assert_eq!(
+            thresholds.level(ThermalLevel::Warning, 76.0),
+            ThermalLevel::Normal
+        );
+        assert_eq!(
+            thresholds.level(ThermalLevel::Normal, 95.0),
+            ThermalLevel::Critical
+        );
+        assert_eq!(
+            thresholds.level(ThermalLevel::Critical, 88.0),
+            ThermalLevel::Critical
+        );
+        assert_eq!(
+            thresholds.level(ThermalLevel::Critical, 86.0),
+            ThermalLevel::Warning
+        );
+    }
+
+    #[test]
+    fn test_gpu_status_file_written_by_the_miner_is_read_back() {
+        let file = std::env::temp_dir().join(format!("gpu_status_{}.json", std::process::id()));
+        // Shape written by the GPU miner's --gpu-status-file option
+        let written = r#"{"gpu_devices":[{"device_name":"NVIDIA GeForce RTX 3080","is_available":true},{"device_name":"Intel(R) UHD Graphics 630","is_available":false,"is_excluded":true}]}"#;
+        std::fs::write(&file, written).expect("status file should be written");
+
+        let devices = read_gpu_status_file(Some(&file));
+        drop(std::fs::remove_file(&file));
+        assert_eq!(devices.len(), 2);
+        assert_eq!(devices[0].device_name, "NVIDIA GeForce RTX 3080");
+        assert!(devices[0].is_available);
+        assert!(!devices[1].is_available);
+        // Files written before devices could be excluded leave the flag out
+        assert!(!devices[0].is_excluded);
+        assert!(devices[1].is_excluded);
+
+        assert!(read_gpu_status_file(None).is_empty());
+    }
+
+    fn card(label: &str, temperature: f32, power: f32) -> HardwareParameters {
+        HardwareParameters {
+            label: label.to_string(),
+            current_temperature: temperature,
+            max_temperature: temperature,
+            power_draw_watts: Some(power),
+            ..Default::default()
+        }
+    }
+
+    fn status(gpu: Vec<HardwareParameters>, rig_id: Option<&str>) -> HardwareStatus {
+        HardwareStatus {
+            cpu: None,
+            gpu,
+            motherboard: vec![],
+            disks: vec![],
+            cpu_availability: Availability::Available,
+            gpu_availability: Availability::Available,
+            temperature_unit: TemperatureUnit::Celsius,
+            implausible_readings: false,
+            sample_time: SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_123),
+            source: ReadingSource::Fresh,
+            rig_id: rig_id.map(str::to_string),
+            alerts: vec![],
+            memory: None,
+            power_source: None,
+            battery_percentage: None,
+        }
+    }
+
+    #[test]
+    fn test_gpus_are_rolled_up_per_vendor() {
+        let status = status(
+            vec![
+                card("NVIDIA GeForce RTX 3080", 70.0, 300.0),
+                card("NVIDIA GeForce RTX 3090", 74.0, 350.0),
+                card("AMD Radeon RX 6800", 68.0, 250.0),
+            ],
+            None,
+        );
+
+        let rollup = status.by_vendor();
+        let nvidia = rollup.get(&GpuVendor::Nvidia).expect("NVIDIA rollup");
+        assert_eq!(nvidia.current_temperature, 72.0);
+        assert_eq!(nvidia.max_temperature, 74.0);
+        assert_eq!(nvidia.power_draw_watts, Some(650.0));
+        let amd = rollup.get(&GpuVendor::Amd).expect("AMD rollup");
+        assert_eq!(amd.power_draw_watts, Some(250.0));
+        assert!(!rollup.contains_key(&GpuVendor::Intel));
+    }
+
+    #[test]
+    fn test_vendor_reported_by_the_backend_wins_over_the_label() {
+        let arc = HardwareParameters {
+            vendor: GpuVendor::Intel,
+            ..card("DG2 [Arc A750]", 55.0, 120.0)
+        };
+        let status = status(vec![arc, card("DG2 [Arc A770]", 60.0, 150.0)], None);
+
+        let rollup = status.by_vendor();
+        assert_eq!(
+            rollup
+                .get(&GpuVendor::Intel)
+                .and_then(|intel| intel.power_draw_watts),
+            Some(120.0)
+        );
+        assert!(rollup.contains_key(&GpuVendor::Unknown));
+    }
+
+    #[test]
+    fn test_aliased_macos_sensors_are_counted_once() {
+        let readings = vec![
+            ("PMU tdie1".to_string(), 50.0),
+            ("PMU TDIE 1".to_string(), 50.0),
+            ("PMU tdie2".to_string(), 50.0),
+            ("pACC MTR Temp Sensor0".to_string(), 62.5),
+            ("pACC MTR Temp Sensor0".to_string(), 62.5),
+            ("pACC MTR Temp Sensor0".to_string(), 64.0),
+        ];
+
+        let unique = dedupe_aliased_sensors(readings);
+        let labels: Vec<&str> = unique.iter().map(|(label, _)| label.as_str()).collect();
+        assert_eq!(
+            labels,
+            vec![
+                "PMU tdie1",
+                "PMU tdie2",
+                "pACC MTR Temp Sensor0",
+                "pACC MTR Temp Sensor0"
+            ]
+        );
+        assert_eq!(unique[3].1, 64.0);
+    }
+
+    #[test]
+    fn test_rigs_are_merged_into_a_fleet_view() {
+        let kelvin_rig = HardwareStatus {
+            temperature_unit: TemperatureUnit::Kelvin,
+            ..status(
+                vec![card("RTX 4090", 80.0, 400.0).with_temperature_unit(TemperatureUnit::Kelvin)],
+                Some("garage"),
+            )
+        };
+        let statuses = vec![
+            status(
+                vec![card("RTX 3080", 60.0, 300.0), card("RTX 3080", 70.0, 320.0)],
+                None,
+            ),
+            kelvin_rig,
+        ];
+
+        let fleet = HardwareStatus::merge(&statuses);
+        assert_eq!(fleet.rig_count, 2);
+        assert_eq!(fleet.gpu_count, 3);
+        assert_eq!(fleet.total_power_draw_watts, Some(1020.0));
+        assert!((fleet.max_temperature.unwrap_or_default() - 80.0).abs() < 0.001);
+        assert!((fleet.average_gpu_temperature.unwrap_or_default() - 70.0).abs() < 0.001);
+        assert_eq!(fleet.rigs[0].rig_id, "rig 1");
+        assert_eq!(fleet.rigs[1].rig_id, "garage");
+        assert_eq!(fleet.rigs[0].average_gpu_temperature, Some(65.0));
+    }
+
+    #[test]
+    fn test_status_sample_time_is_written_as_epoch_millis() {
+        let status = status(vec![], None);
+
+        let mut json = serde_json::to_value(&status).expect("status should serialize");
+        assert_eq!(
+            json.get("sample_time"),
+            Some(&serde_json::json!(1_700_000_000_123_u64))
+        );
+
+        let round_trip: HardwareStatus =
+            serde_json::from_value(json.clone()).expect("status should deserialize");
+        assert_eq!(round_trip.sample_time, status.sample_time);
+
+        // Statuses logged before keep serde's default representation
+        json["sample_time"] = serde_json::json!({
+            "secs_since_epoch": 1_700_000_000_u64,
+            "nanos_since_epoch": 123_000_000,
+        });
+        let logged: HardwareStatus =
+            serde_json::from_value(json).expect("old status should deserialize");
+        assert_eq!(logged.sample_time, status.sample_time);
+    }
+
+    #[test]
+    fn test_recorded_session_is_read_back() {
+        let session = vec![
+            HardwareStatus {
+                cpu: Some(card("AMD Ryzen 9 5950X", 55.0, 105.0)),
+                ..status(vec![card("RTX 3080", 65.0, 220.0)], Some("garage"))
+            },
+            status(vec![card("RTX 3080", 82.0, 310.0)], Some("garage")),
+        ];
+
+        let json = serde_json::to_string(&session).expect("session should serialize");
+        let replayed: Vec<HardwareStatus> =
+            serde_json::from_str(&json).expect("session should deserialize");
+        assert_eq!(replayed.len(), 2);
+        assert_eq!(
+            replayed[0].cpu.as_ref().map(|cpu| cpu.label.as_str()),
+            Some("AMD Ryzen 9 5950X")
+        );
+        assert!(replayed[1].cpu.is_none());
+        assert_eq!(replayed[1].gpu[0].current_temperature, 82.0);
+        assert_eq!(replayed[1].rig_id.as_deref(), Some("garage"));
+    }
+
+    #[test]
+    fn test_missing_optional_fields_round_trip_as_none() {
+        let parameters = card("RTX 3080", 65.0, 220.0);
+
+        let json = serde_json::to_value(&parameters).expect("parameters should serialize");
+        assert!(json.get("uuid").is_none());
+        assert!(json.get("tdp_watts").is_none());
+        assert_eq!(
+            json.get("power_draw_watts"),
+            Some(&serde_json::json!(220.0))
+        );
+
+        let round_trip: HardwareParameters =
+            serde_json::from_value(json).expect("parameters should deserialize");
+        assert_eq!(round_trip.uuid, None);
+        assert_eq!(round_trip.tdp_watts, None);
+        assert_eq!(round_trip.cpu_tjmax, None);
+        assert_eq!(round_trip.power_draw_watts, Some(220.0));
+    }
+
+    #[test]
+    fn test_parameters_logged_without_newer_fields_are_read_back() {
+        let json = serde_json::json!({
+            "label": "RTX 3080",
+            "usage_percentage": 99.0,
+            "current_temperature": 65.0,
+            "max_temperature": 70.0,
+        });
+
+        let logged: HardwareParameters =
+            serde_json::from_value(json).expect("old parameters should deserialize");
+        assert!(logged.sockets.is_empty());
+        assert!(!logged.is_integrated);
+        assert!(logged.fan_speeds_percentage.is_empty());
+        assert!(logged.core_usages.is_empty());
+        assert!(logged.throttle_reasons.is_empty());
+    }
+
+    #[test]
+    fn test_hardware_status_one_line_summary() {
+        let mut status = status(
+            vec![
+                HardwareParameters {
+                    usage_percentage: 99.4,
+                    ..card("NVIDIA GeForce RTX 3080", 68.0, 300.0)
+                },
+                HardwareParameters {
+                    usage_percentage: 98.0,
+                    ..card("NVIDIA GeForce RTX 3070", 71.2, 220.0)
+                },
+            ],
+            None,
+        );
+        status.cpu = Some(HardwareParameters {
+            label: "AMD Ryzen 9 5950X".to_string(),
+            usage_percentage: 45.0,
+            current_temperature: 62.0,
+            ..Default::default()
+        });
+        assert_eq!(
+            status.to_string(),
+            "CPU 45% 62°C | GPU0 99% 68°C | GPU1 98% 71°C"
+        );
+        assert_eq!(
+            status.gpu[1].to_string(),
+            "NVIDIA GeForce RTX 3070 98% 71°C"
+        );
+
+        status.cpu = None;
+        status.temperature_unit = TemperatureUnit::Kelvin;
+        assert_eq!(status.to_string(), "GPU0 99% 68K | GPU1 98% 71K");
+    }
+
+    #[test]
+    fn test_throttle_duration_accumulates_until_throttling_stops() {
+        let mut monitor = mock_monitor(vec![], vec![]);
+        let throttled = |reasons: &[&str]| {
+            vec![HardwareParameters {
+                throttle_reasons: reasons.iter().map(|reason| reason.to_string()).collect(),
+                ..gpu("GPU-a", 80.0)
+            }]
+        };
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let durations: Vec<u64> = [
+            (0, throttled(&["HwThermalSlowdown"])),
+            (1_500, throttled(&["HwThermalSlowdown", "SwPowerCap"])),
+            (4_000, throttled(&["SwPowerCap"])),
+            (5_000, throttled(&["GpuIdle"])),
+            (6_000, throttled(&["SwThermalSlowdown"])),
+        ]
+        .into_iter()
+        .map(|(offset_ms, mut gpus)| {
+            monitor.track_throttling(start + Duration::from_millis(offset_ms), &mut gpus);
+            gpus[0].throttle_duration_ms
+        })
+        .collect();
+        assert_eq!(durations, vec![0, 1_500, 4_000, 0, 0]);
+    }
+
+    #[test]
+    fn test_ccd_temperatures_are_averaged_with_their_maximum() {
+        assert_eq!(ccd_temperature_summary(&[58.0, 66.0]), Some((62.0, 66.0)));
+        // Older Zen chips only report Tctl
+        assert_eq!(ccd_temperature_summary(&[]), None);
+    }
+
+    #[test]
+    fn test_disk_temperatures_are_reported_with_the_status() {
+        let file = std::env::temp_dir().join(format!("replay_disks_{}.json", std::process::id()));
+        let recorded = HardwareStatus {
+            disks: vec![card("nvme Composite", 48.0, 0.0)],
+            ..status(vec![], None)
+        };
+        std::fs::write(
+            &file,
+            serde_json::to_string(&vec![recorded]).expect("session should serialize"),
+        )
+        .expect("replay file should be written");
+
+        let mut monitor = mock_monitor(vec![], vec![]);
+        let started = monitor.start_replay(file.clone(), true);
+        drop(std::fs::remove_file(&file));
+        started.expect("replay should start");
+
+        let status = monitor.read_hardware_parameters_blocking();
+        assert_eq!(status.disks.len(), 1);
+        // Shown under the friendly name of the sensor
+        assert_eq!(status.disks[0].label, "NVMe SSD");
+        assert_eq!(status.disks[0].current_temperature, 48.0);
+    }
+
+    #[test]
+    fn test_temperature_trend_is_projected_over_the_horizon() {
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let sample = |offset_secs: u64, temperature: f32| HistorySample {
+            sample_time: start + Duration::from_secs(offset_secs),
+            usage_percentage: 100.0,
+            temperature,
+        };
+        let heating = [sample(0, 70.0), sample(5, 72.0), sample(10, 74.0)];
+        // 0.4°C per second from 74°C reaches 86°C after 30 seconds
+        assert_eq!(
+            projected_temperature(&heating, Duration::from_secs(30)),
+            Some(86.0)
+        );
+
+        let cooling = [sample(0, 80.0), sample(10, 75.0)];
+        assert_eq!(
+            projected_temperature(&cooling, Duration::from_secs(30)),
+            Some(60.0)
+        );
+        assert_eq!(
+            projected_temperature(&[sample(0, 70.0)], Duration::from_secs(30)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_every_section_of_a_replayed_poll_comes_from_the_same_row() {
+        let file = std::env::temp_dir().join(format!("replay_rows_{}.json", std::process::id()));
+        let row = |temperature: f32, gpu_availability: Availability| HardwareStatus {
+            cpu: Some(card("AMD Ryzen 9 5950X", temperature, 0.0)),
+            motherboard: vec![card("Motherboard", temperature, 0.0)],
+            disks: vec![card("nvme Composite", temperature, 0.0)],
+            gpu_availability,
+            ..status(vec![card("RTX 3080", temperature, 220.0)], None)
+        };
+        let session = vec![
+            row(40.0, Availability::Available),
+            row(50.0, Availability::Unavailable),
+        ];
+        std::fs::write(
+            &file,
+            serde_json::to_string(&session).expect("session should serialize"),
+        )
+        .expect("replay file should be written");
+
+        let mut monitor = mock_monitor(vec![], vec![]);
+        let started = monitor.start_replay(file.clone(), false);
+        drop(std::fs::remove_file(&file));
+        started.expect("replay should start");
+
+        for (temperature, gpu_availability) in [
+            (40.0, Availability::Available),
+            (50.0, Availability::Unavailable),
+        ] {
+            let status = monitor.read_hardware_parameters_blocking();
+            assert_eq!(
+                status.cpu.map(|cpu| cpu.current_temperature),
+                Some(temperature)
+            );
+            assert_eq!(status.gpu[0].current_temperature, temperature);
+            assert_eq!(status.motherboard[0].current_temperature, temperature);
+            assert_eq!(status.disks[0].current_temperature, temperature);
+            assert_eq!(status.gpu_availability, gpu_availability);
+        }
+    }
+
+    #[test]
+    fn test_replay_without_cpu_or_rows_left_reports_sensors_unavailable() {
+        let file = std::env::temp_dir().join(format!("replay_end_{}.json", std::process::id()));
+        let session = vec![status(vec![card("RTX 3080", 65.0, 220.0)], None)];
+        std::fs::write(
+            &file,
+            serde_json::to_string(&session).expect("session should serialize"),
+        )
+        .expect("replay file should be written");
+
+        let mut monitor = mock_monitor(vec![], vec![]);
+        let started = monitor.start_replay(file.clone(), false);
+        drop(std::fs::remove_file(&file));
+        started.expect("replay should start");
+
+        let recorded = monitor.read_hardware_parameters_blocking();
+        assert!(recorded.cpu.is_none());
+        assert_eq!(recorded.cpu_availability, Availability::Unavailable);
+        assert_eq!(recorded.gpu_availability, Availability::Available);
+
+        let finished = monitor.read_hardware_parameters_blocking();
+        assert!(finished.gpu.is_empty());
+        assert_eq!(finished.cpu_availability, Availability::Unavailable);
+        assert_eq!(finished.gpu_availability, Availability::Unavailable);
+    }
+
+    #[test]
+    fn test_replay_recorded_in_fahrenheit_is_read_as_celsius() {
+        let file = std::env::temp_dir().join(format!("replay_unit_{}.json", std::process::id()));
+        let recorded = HardwareStatus {
+            temperature_unit: TemperatureUnit::Fahrenheit,
+            ..status(
+                vec![card("RTX 3080", 65.0, 220.0)
+                    .with_temperature_unit(TemperatureUnit::Fahrenheit)],
+                None,
+            )
+        };
+        std::fs::write(
+            &file,
+            serde_json::to_string(&vec![recorded]).expect("session should serialize"),
+        )
+        .expect("replay file should be written");
+
+        let mut monitor = mock_monitor(vec![], vec![]);
+        let started = monitor.start_replay(file.clone(), true);
+        drop(std::fs::remove_file(&file));
+        started.expect("replay should start");
+
+        let status = monitor.read_hardware_parameters_blocking();
+        assert_eq!(status.temperature_unit, TemperatureUnit::Celsius);
+        assert!((status.gpu[0].current_temperature - 65.0).abs() < 0.01);
+    }
+}
@@ -0,0 +1,21 @@
+#[cfg(test)]
+mod tests {
+    use crate::ioreg::parse_output;
+
+    #[test]
+    fn test_parse_ioreg_device_utilization() {
+        let output = r#"+-o AGXAcceleratorG13X  <class AGXAcceleratorG13X, id 0x100000a2d, registered, matched, active, busy 0 (0 ms), retain 62>
+    {
+      "model" = "Apple M1 Pro"
+      "PerformanceStatistics" = {"In use system memory"=94371840,"Tiler Utilization %"=4,"Renderer Utilization %"=11,"Device Utilization %"=12,"Allocated PB Size"=1835008}
+    }
+"#;
+
+        assert_eq!(parse_output(output), vec![12.0]);
+    }
+
+    #[test]
+    fn test_parse_ioreg_without_statistics() {
+        assert!(parse_output("+-o IOAccelerator\n    {\n    }\n").is_empty());
+    }
+}
@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use crate::libre_hardware_monitor::{parse_sensors, snapshot, LibreHardwareMonitorSensor};
+
+    /// Trimmed WMI query output of a Ryzen system with a Nuvoton Super I/O chip
+    const WMI_OUTPUT: &str = r#"[
+        {"Name":"Core (Tctl/Tdie)","Identifier":"/amdcpu/0/temperature/2","SensorType":"Temperature","Value":61.5},
+        {"Name":"CCD1 (Tdie)","Identifier":"/amdcpu/0/temperature/3","SensorType":"Temperature","Value":58.3},
+        {"Name":"CPU Total","Identifier":"/amdcpu/0/load/0","SensorType":"Load","Value":12.5},
+        {"Name":"Motherboard","Identifier":"/lpc/nct6798d/temperature/0","SensorType":"Temperature","Value":38.0},
+        {"Name":"VRM MOS","Identifier":"/lpc/nct6798d/temperature/4","SensorType":"Temperature","Value":47.5},
+        {"Name":"Auxiliary","Identifier":"/lpc/nct6798d/temperature/5","SensorType":"Temperature","Value":null},
+        {"Name":"CPU Fan","Identifier":"/lpc/nct6798d/fan/1","SensorType":"Fan","Value":1250.0},
+        {"Name":"CPU Fan","Identifier":"/lpc/nct6798d/control/1","SensorType":"Control","Value":44.7},
+        {"Name":"System Fan #1","Identifier":"/lpc/nct6798d/control/2","SensorType":"Control","Value":60.0}
+    ]
+"#;
+
+    #[test]
+    fn test_snapshot_prefers_the_cpu_package_sensor() {
+        let sensors = parse_sensors(WMI_OUTPUT).expect("WMI output should parse");
+        // Loads, fan speeds in RPM and sensors without a value are left out
+        assert_eq!(sensors.len(), 6);
+
+        let snapshot = snapshot(&sensors);
+        assert_eq!(snapshot.cpu_temperature, Some(61.5));
+        assert_eq!(snapshot.cpu_fan_speeds_percentage, vec![45]);
+        assert_eq!(
+            snapshot.motherboard_temperatures,
+            vec![
+                LibreHardwareMonitorSensor {
+                    name: "Motherboard".to_string(),
+                    sensor_id: "/lpc/nct6798d/temperature/0".to_string(),
+                    value: 38.0,
+                },
+                LibreHardwareMonitorSensor {
+                    name: "VRM MOS".to_string(),
+                    sensor_id: "/lpc/nct6798d/temperature/4".to_string(),
+                    value: 47.5,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_snapshot_averages_cpu_sensors_without_a_package_sensor() {
+        let core = |value: f32| LibreHardwareMonitorSensor {
+            name: "CPU Core #1".to_string(),
+            sensor_id: "/intelcpu/0/temperature/0".to_string(),
+            value,
+        };
+        assert_eq!(
+            snapshot(&[core(50.0), core(60.0)]).cpu_temperature,
+            Some(55.0)
+        );
+        assert_eq!(snapshot(&[]).cpu_temperature, None);
+    }
+}
@@ -1 +1,8 @@
 mod app_config_tests;
+mod hardware_csv_logger_tests;
+mod hardware_monitor_tests;
+mod ioreg_tests;
+mod libre_hardware_monitor_tests;
+mod nvidia_smi_tests;
+mod power_supply_tests;
+mod prometheus_metrics_tests;
@@ -0,0 +1,37 @@
+#[cfg(test)]
+mod tests {
+    use crate::nvidia_smi::{parse_output, NvidiaSmiGpu};
+
+    #[test]
+    fn test_parse_nvidia_smi_csv_rows() {
+        let output =
+            "NVIDIA GeForce RTX 3080, GPU-5c1f0d2e-8a4b-4c3d-9e7f-1a2b3c4d5e6f, 65, 98, 312.45\n\
+            NVIDIA GeForce GTX 1080, [N/A], 54, 0, [N/A]\n";
+
+        let gpus = parse_output(output);
+        assert_eq!(
+            gpus,
+            vec![
+                NvidiaSmiGpu {
+                    name: "NVIDIA GeForce RTX 3080".to_string(),
+                    uuid: Some("GPU-5c1f0d2e-8a4b-4c3d-9e7f-1a2b3c4d5e6f".to_string()),
+                    temperature: 65.0,
+                    usage_percentage: 98.0,
+                    power_draw_watts: Some(312.45),
+                },
+                NvidiaSmiGpu {
+                    name: "NVIDIA GeForce GTX 1080".to_string(),
+                    uuid: None,
+                    temperature: 54.0,
+                    usage_percentage: 0.0,
+                    power_draw_watts: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_nvidia_smi_skips_malformed_rows() {
+        assert!(parse_output("No devices were found\n").is_empty());
+    }
+}
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::power_supply::{
+        parse_pmset_output, read_sysfs_power_status, PowerSource, PowerStatus,
+    };
+
+    #[test]
+    fn test_laptop_power_supplies_are_read_from_sysfs() {
+        let dir = std::env::temp_dir().join(format!("power_supply_{}", std::process::id()));
+        let supplies = [
+            ("AC", vec![("type", "Mains"), ("online", "0")]),
+            ("BAT0", vec![("type", "Battery"), ("capacity", "76")]),
+            // A wireless mouse, not the laptop's battery
+            (
+                "hidpp_battery_0",
+                vec![("type", "Battery"), ("scope", "Device"), ("capacity", "5")],
+            ),
+        ];
+        for (supply, attributes) in &supplies {
+            fs::create_dir_all(dir.join(supply)).expect("supply dir should be created");
+            for (attribute, value) in attributes {
+                fs::write(dir.join(supply).join(attribute), format!("{}\n", value))
+                    .expect("attribute should be written");
+            }
+        }
+        let on_battery = read_sysfs_power_status(&dir);
+        fs::write(dir.join("AC").join("online"), "1\n").expect("attribute should be written");
+        let plugged_in = read_sysfs_power_status(&dir);
+        fs::remove_dir_all(dir.join("BAT0")).expect("battery dir should be removed");
+        let desktop = read_sysfs_power_status(&dir);
+        drop(fs::remove_dir_all(&dir));
+
+        assert_eq!(
+            on_battery,
+            Some(PowerStatus {
+                power_source: PowerSource::Battery,
+                battery_percentage: Some(76.0),
+            })
+        );
+        assert_eq!(
+            plugged_in.map(|status| status.power_source),
+            Some(PowerSource::Ac)
+        );
+        assert_eq!(desktop, None);
+    }
+
+    #[test]
+    fn test_parse_pmset_battery_output() {
+        let output = "Now drawing from 'Battery Power'\n \
+            -InternalBattery-0 (id=4653155)\t85%; discharging; 5:12 remaining present: true\n";
+        assert_eq!(
+            parse_pmset_output(output),
+            Some(PowerStatus {
+                power_source: PowerSource::Battery,
+                battery_percentage: Some(85.0),
+            })
+        );
+        // Desktops only list their AC source
+        assert_eq!(parse_pmset_output("Now drawing from 'AC Power'\n"), None);
+    }
+}
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use crate::hardware_monitor::HardwareParameters;
+    use crate::prometheus_metrics::{render, request_target};
+
+    #[test]
+    fn test_render_prometheus_text_exposition() {
+        let cpu = HardwareParameters {
+            label: "AMD Ryzen 9 5950X".to_string(),
+            usage_percentage: 87.5,
+            current_temperature: 61.0,
+            ..Default::default()
+        };
+        let gpu = vec![
+            HardwareParameters {
+                label: "NVIDIA GeForce RTX 3080".to_string(),
+                uuid: Some("GPU-a".to_string()),
+                usage_percentage: 98.0,
+                current_temperature: 63.0,
+                power_draw_watts: Some(312.5),
+                fan_speed_percentage: Some(70),
+                ..Default::default()
+            },
+            HardwareParameters {
+                label: "Radeon \"RX\" 6800".to_string(),
+                usage_percentage: 12.0,
+                current_temperature: 48.0,
+                ..Default::default()
+            },
+        ];
+
+        let output = render(Some(&cpu), &gpu);
+        assert!(output.contains("# TYPE universe_cpu_temperature_celsius gauge\n"));
+        assert!(output.contains("universe_cpu_usage_percent{device=\"AMD Ryzen 9 5950X\"} 87.5\n"));
+        assert!(output.contains(
+            "universe_gpu_temperature_celsius{device=\"NVIDIA GeForce RTX 3080\",uuid=\"GPU-a\"} 63\n"
+        ));
+        assert!(output
+            .contains("universe_gpu_temperature_celsius{device=\"Radeon \\\"RX\\\" 6800\"} 48\n"));
+        assert!(output.contains(
+            "universe_gpu_power_watts{device=\"NVIDIA GeForce RTX 3080\",uuid=\"GPU-a\"} 312.5\n"
+        ));
+        assert!(output.contains(
+            "universe_gpu_fan_speed_percent{device=\"NVIDIA GeForce RTX 3080\",uuid=\"GPU-a\"} 70\n"
+        ));
+        // No device reports a GPU memory temperature
+        assert!(!output.contains("universe_gpu_memory_temperature_celsius"));
+    }
+
+    #[test]
+    fn test_request_target_of_the_request_line() {
+        assert_eq!(
+            request_target("GET /metrics?format=text HTTP/1.1\r\nHost: localhost\r\n\r\n"),
+            Some(("GET", "/metrics"))
+        );
+        assert_eq!(
+            request_target("GET /favicon.ico HTTP/1.1\r\n\r\n"),
+            Some(("GET", "/favicon.ico"))
+        );
+        assert_eq!(request_target("\r\n\r\n"), None);
+        assert_eq!(request_target("GET /metrics\r\n\r\n"), None);
+    }
+}